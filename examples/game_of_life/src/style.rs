@@ -157,6 +157,7 @@ impl pick_list::StyleSheet for PickList {
             }
             .into(),
             selected_text_color: Color::WHITE,
+            ..pick_list::Menu::default()
         }
     }
 
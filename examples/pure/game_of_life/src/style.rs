@@ -154,6 +154,7 @@ impl pick_list::StyleSheet for PickList {
             }
             .into(),
             selected_text_color: Color::WHITE,
+            ..pick_list::Menu::default()
         }
     }
 
@@ -11,6 +11,14 @@ pub trait Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     fn write(&mut self, contents: String);
+
+    /// Reads the current content of the primary selection as text.
+    ///
+    /// The primary selection is only available on some platforms, like
+    /// X11 and Wayland. Nothing is read by default.
+    fn read_primary(&self) -> Option<String> {
+        None
+    }
 }
 
 /// A null implementation of the [`Clipboard`] trait.
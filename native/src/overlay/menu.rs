@@ -10,10 +10,13 @@ use crate::touch;
 use crate::widget::scrollable::{self, Scrollable};
 use crate::widget::Container;
 use crate::{
-    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
-    Shell, Size, Vector, Widget,
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::borrow::Cow;
+use std::ops::Range;
+
 pub use iced_style::menu::Style;
 
 /// A list of selectable options.
@@ -25,9 +28,23 @@ pub struct Menu<'a, T, Renderer: text::Renderer> {
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
     width: u16,
+    max_height: Option<u16>,
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
+    trailing_label: Option<Box<dyn Fn(&T) -> String + 'a>>,
+    option_background: Option<Box<dyn Fn(&T) -> Option<Background> + 'a>>,
+    option_color: Option<Box<dyn Fn(&T) -> Option<Color> + 'a>>,
+    option_match: Option<OptionMatch<'a, T>>,
+    option_enabled: Option<Box<dyn Fn(&T) -> bool + 'a>>,
+    group_by: Option<GroupBy<'a, T>>,
+    group_header_color: Option<Color>,
+    checkmark: Option<Checkmark<'a, T>>,
+    checkboxes: Option<Checkmark<'a, T>>,
+    empty_message_height: Option<u16>,
+    center_empty_message: bool,
+    empty_message_color: Option<Color>,
+    right_to_left: bool,
     style: Style,
 }
 
@@ -52,9 +69,23 @@ where
             hovered_option,
             last_selection,
             width: 0,
+            max_height: None,
             padding: Padding::ZERO,
             text_size: None,
             font: Default::default(),
+            trailing_label: None,
+            option_background: None,
+            option_color: None,
+            option_match: None,
+            option_enabled: None,
+            group_by: None,
+            group_header_color: None,
+            checkmark: None,
+            checkboxes: None,
+            empty_message_height: None,
+            center_empty_message: false,
+            empty_message_color: None,
+            right_to_left: false,
             style: Default::default(),
         }
     }
@@ -65,6 +96,14 @@ where
         self
     }
 
+    /// Sets the maximum height of the [`Menu`].
+    ///
+    /// The options become scrollable past this height.
+    pub fn max_height(mut self, max_height: u16) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
     /// Sets the [`Padding`] of the [`Menu`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -83,6 +122,133 @@ where
         self
     }
 
+    /// Sets the function that produces a label to display on the right side
+    /// of every option of the [`Menu`].
+    pub fn trailing_label(
+        mut self,
+        trailing_label: impl Fn(&T) -> String + 'a,
+    ) -> Self {
+        self.trailing_label = Some(Box::new(trailing_label));
+        self
+    }
+
+    /// Sets the function that produces the background of every option of
+    /// the [`Menu`] that is not hovered.
+    pub fn option_background(
+        mut self,
+        option_background: impl Fn(&T) -> Option<Background> + 'a,
+    ) -> Self {
+        self.option_background = Some(Box::new(option_background));
+        self
+    }
+
+    /// Sets the function that produces the text color of every option of
+    /// the [`Menu`].
+    ///
+    /// The [`Style`] colors are used when it returns `None`.
+    pub fn option_color(
+        mut self,
+        option_color: impl Fn(&T) -> Option<Color> + 'a,
+    ) -> Self {
+        self.option_color = Some(Box::new(option_color));
+        self
+    }
+
+    /// Sets the function that produces the byte range of the label of every
+    /// option of the [`Menu`] that should be emphasized with the given color.
+    ///
+    /// Hovered options are displayed without emphasis.
+    pub fn option_match(
+        mut self,
+        option_match: impl Fn(&T) -> Option<Range<usize>> + 'a,
+        color: Color,
+    ) -> Self {
+        self.option_match = Some((Box::new(option_match), color));
+        self
+    }
+
+    /// Sets the function that decides whether every option of the [`Menu`]
+    /// can be selected.
+    ///
+    /// Disabled options are displayed dimmed and can neither be hovered nor
+    /// selected. All the options are enabled by default.
+    pub fn option_enabled(
+        mut self,
+        option_enabled: impl Fn(&T) -> bool + 'a,
+    ) -> Self {
+        self.option_enabled = Some(Box::new(option_enabled));
+        self
+    }
+
+    /// Sets the function that produces the group of every option of the
+    /// [`Menu`].
+    ///
+    /// A header with the name of the group is displayed before every run of
+    /// consecutive options in the same group, so the options should already
+    /// be sorted by group. Headers can neither be hovered nor selected.
+    pub fn group_by(
+        mut self,
+        group_by: impl Fn(&T) -> Cow<'_, str> + 'a,
+    ) -> Self {
+        self.group_by = Some(Box::new(group_by));
+        self
+    }
+
+    /// Sets the text color of the group headers of the [`Menu`].
+    pub fn group_header_color(mut self, color: Color) -> Self {
+        self.group_header_color = Some(color);
+        self
+    }
+
+    /// Sets the currently selected option of the [`Menu`], displaying a
+    /// checkmark with the given color on its right side.
+    pub fn selected_option(mut self, selected: &'a T, color: Color) -> Self
+    where
+        T: PartialEq,
+    {
+        self.checkmark =
+            Some((Box::new(move |option: &T| option == selected), color));
+        self
+    }
+
+    /// Sets the function that decides whether every option of the [`Menu`]
+    /// is checked, displaying a checkbox with the given color on the left
+    /// side of every option.
+    pub fn checkboxes(
+        mut self,
+        is_checked: impl Fn(&T) -> bool + 'a,
+        color: Color,
+    ) -> Self {
+        self.checkboxes = Some((Box::new(is_checked), color));
+        self
+    }
+
+    /// Sets the height of the row displaying the message of an empty
+    /// [`Menu`].
+    pub fn empty_message_height(mut self, height: u16) -> Self {
+        self.empty_message_height = Some(height);
+        self
+    }
+
+    /// Sets whether the message of an empty [`Menu`] should be centered.
+    pub fn center_empty_message(mut self, center: bool) -> Self {
+        self.center_empty_message = center;
+        self
+    }
+
+    /// Sets the text color of the message of an empty [`Menu`].
+    pub fn empty_message_color(mut self, color: Color) -> Self {
+        self.empty_message_color = Some(color);
+        self
+    }
+
+    /// Sets whether the rows of the [`Menu`] should be laid out from right
+    /// to left, with their labels aligned to the right.
+    pub fn right_to_left(mut self, right_to_left: bool) -> Self {
+        self.right_to_left = right_to_left;
+        self
+    }
+
     /// Sets the style of the [`Menu`].
     pub fn style(mut self, style: impl Into<Style>) -> Self {
         self.style = style.into();
@@ -110,7 +276,10 @@ where
 /// The local state of a [`Menu`].
 #[derive(Debug, Clone, Default)]
 pub struct State {
-    scrollable: scrollable::State,
+    pub(crate) scrollable: scrollable::State,
+    /// The option under the mouse, kept apart from the hovered option that
+    /// follows the keyboard.
+    pub(crate) pointed_option: Option<usize>,
 }
 
 impl State {
@@ -118,11 +287,94 @@ impl State {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Scrolls the [`Menu`] so the option at the given index is visible.
+    ///
+    /// The options are grouped with the given function, if any, so the
+    /// group headers displayed before the option are taken into account.
+    pub(crate) fn scroll_to_option<T>(
+        &mut self,
+        options: &[T],
+        index: usize,
+        group_by: Option<&dyn Fn(&T) -> Cow<'_, str>>,
+    ) {
+        let rows = rows(options, group_by);
+        let row = rows
+            .iter()
+            .position(
+                |row| matches!(row, Row::Option(option) if *option == index),
+            )
+            .unwrap_or(0);
+
+        if rows.len() > 1 {
+            self.scrollable
+                .snap_to(row as f32 / (rows.len() - 1) as f32);
+        }
+    }
+}
+
+/// Returns the rows displaying the given options, inserting a header before
+/// every group of options if they are grouped with the given function.
+fn rows<'a, T>(
+    options: &'a [T],
+    group_by: Option<&dyn Fn(&T) -> Cow<'_, str>>,
+) -> Vec<Row<'a>> {
+    let group_by = match group_by {
+        Some(group_by) => group_by,
+        None => return (0..options.len()).map(Row::Option).collect(),
+    };
+
+    let mut rows = Vec::with_capacity(options.len());
+    let mut current_group = None;
+
+    for (index, option) in options.iter().enumerate() {
+        let group = group_by(option);
+
+        if current_group.as_ref() != Some(&group) {
+            rows.push(Row::Header(group.clone()));
+            current_group = Some(group);
+        }
+
+        rows.push(Row::Option(index));
+    }
+
+    rows
+}
+
+/// The function producing the emphasized range of an option, and the color
+/// of the emphasis.
+type OptionMatch<'a, T> = (Box<dyn Fn(&T) -> Option<Range<usize>> + 'a>, Color);
+
+/// The function deciding whether an option is the selected one, and the
+/// color of its checkmark.
+type Checkmark<'a, T> = (Box<dyn Fn(&T) -> bool + 'a>, Color);
+
+/// The function producing the group of an option.
+type GroupBy<'a, T> = Box<dyn Fn(&T) -> Cow<'_, str> + 'a>;
+
+/// The scrollbar appearance of a [`Menu`], taken from its [`Style`].
+struct ScrollbarStyle(Style);
+
+impl scrollable::StyleSheet for ScrollbarStyle {
+    fn active(&self) -> scrollable::style::Scrollbar {
+        self.0.scrollbar
+    }
+
+    fn hovered(&self) -> scrollable::style::Scrollbar {
+        self.0.hovered_scrollbar
+    }
+}
+
+/// A row of the list of a [`Menu`].
+enum Row<'a> {
+    Header(Cow<'a, str>),
+    Option(usize),
 }
 
 struct Overlay<'a, Message, Renderer: text::Renderer> {
     container: Container<'a, Message, Renderer>,
     width: u16,
+    max_height: Option<u16>,
     target_height: f32,
     style: Style,
 }
@@ -143,28 +395,61 @@ where
             hovered_option,
             last_selection,
             width,
+            max_height,
             padding,
             font,
             text_size,
+            trailing_label,
+            option_background,
+            option_color,
+            option_match,
+            option_enabled,
+            group_by,
+            group_header_color,
+            checkmark,
+            checkboxes,
+            empty_message_height,
+            center_empty_message,
+            empty_message_color,
+            right_to_left,
             style,
         } = menu;
 
-        let container =
-            Container::new(Scrollable::new(&mut state.scrollable).push(List {
-                options,
-                options_empty_message,
-                hovered_option,
-                last_selection,
-                font,
-                text_size,
-                padding,
-                style: style.clone(),
-            }))
-            .padding(1);
+        let scrollable = Scrollable::new(&mut state.scrollable)
+            .scrollbar_width(style.scrollbar_width)
+            .scroller_width(style.scroller_width)
+            .style(ScrollbarStyle(style));
+
+        let container = Container::new(scrollable.push(List {
+            options,
+            options_empty_message,
+            hovered_option,
+            pointed_option: &mut state.pointed_option,
+            last_selection,
+            font,
+            text_size,
+            padding,
+            trailing_label,
+            option_background,
+            option_color,
+            option_match,
+            option_enabled,
+            group_by,
+            group_header_color,
+            checkmark,
+            checkboxes,
+            empty_message_height,
+            center_empty_message,
+            empty_message_color,
+            right_to_left,
+            style: style.clone(),
+        }))
+        .padding(1);
 
         Self {
             container,
             width: width,
+            max_height,
             target_height,
             style: style,
         }
@@ -185,22 +470,30 @@ where
         let space_below = bounds.height - (position.y + self.target_height);
         let space_above = position.y;
 
-        let limits = layout::Limits::new(
-            Size::ZERO,
-            Size::new(
-                bounds.width - position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
-            ),
-        )
-        .width(Length::Units(self.width));
+        let max_height = self.max_height.map(f32::from);
+
+        let layout = |available_height: f32| {
+            let limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new(
+                    bounds.width - position.x,
+                    max_height.map_or(available_height, |max_height| {
+                        available_height.min(max_height)
+                    }),
+                ),
+            )
+            .width(Length::Units(self.width));
+
+            self.container.layout(renderer, &limits)
+        };
+
+        // The menu is only placed on top if it does not fit under the target
+        let is_below = space_below >= space_above
+            || layout(f32::INFINITY).size().height <= space_below;
 
-        let mut node = self.container.layout(renderer, &limits);
+        let mut node = layout(if is_below { space_below } else { space_above });
 
-        node.move_to(if space_below > space_above {
+        node.move_to(if is_below {
             position + Vector::new(0.0, self.target_height)
         } else {
             position - Vector::new(0.0, node.size().height)
@@ -271,13 +564,196 @@ struct List<'a, T, Renderer: text::Renderer> {
     options: &'a [T],
     options_empty_message: &'a Option<String>,
     hovered_option: &'a mut Option<usize>,
+    pointed_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
+    trailing_label: Option<Box<dyn Fn(&T) -> String + 'a>>,
+    option_background: Option<Box<dyn Fn(&T) -> Option<Background> + 'a>>,
+    option_color: Option<Box<dyn Fn(&T) -> Option<Color> + 'a>>,
+    option_match: Option<OptionMatch<'a, T>>,
+    option_enabled: Option<Box<dyn Fn(&T) -> bool + 'a>>,
+    group_by: Option<GroupBy<'a, T>>,
+    group_header_color: Option<Color>,
+    checkmark: Option<Checkmark<'a, T>>,
+    checkboxes: Option<Checkmark<'a, T>>,
+    empty_message_height: Option<u16>,
+    center_empty_message: bool,
+    empty_message_color: Option<Color>,
+    right_to_left: bool,
     style: Style,
 }
 
+impl<'a, T, Renderer> List<'a, T, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn empty_message(&self) -> Option<&'a str> {
+        if self.options.is_empty() {
+            self.options_empty_message.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the rows of the list, inserting a header before every group
+    /// of options.
+    fn rows(&self) -> Vec<Row<'a>> {
+        rows(self.options, self.group_by.as_deref())
+    }
+
+    /// Returns where the labels of a row start and how they are aligned,
+    /// which is mirrored when the list is laid out from right to left.
+    fn row_start(&self, bounds: Rectangle) -> (f32, alignment::Horizontal) {
+        if self.right_to_left {
+            (
+                bounds.x + bounds.width - f32::from(self.padding.right),
+                alignment::Horizontal::Right,
+            )
+        } else {
+            (
+                bounds.x + f32::from(self.padding.left),
+                alignment::Horizontal::Left,
+            )
+        }
+    }
+
+    /// Returns where the checkmarks and trailing labels of a row end and
+    /// how they are aligned.
+    fn row_end(&self, bounds: Rectangle) -> (f32, alignment::Horizontal) {
+        if self.right_to_left {
+            (
+                bounds.x + f32::from(self.padding.left),
+                alignment::Horizontal::Left,
+            )
+        } else {
+            (
+                bounds.x + bounds.width - f32::from(self.padding.right),
+                alignment::Horizontal::Right,
+            )
+        }
+    }
+
+    fn is_enabled(&self, option: &T) -> bool {
+        self.option_enabled
+            .as_ref()
+            .map_or(true, |option_enabled| option_enabled(option))
+    }
+
+    /// Returns the index of the option under the given position, unless it
+    /// is disabled or there is a group header instead.
+    fn hoverable_option(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        text_size: u16,
+    ) -> Option<usize> {
+        let row = ((cursor_position.y - bounds.y)
+            / f32::from(text_size + self.padding.vertical()))
+            as usize;
+
+        match self.rows().get(row)? {
+            Row::Option(index) if self.is_enabled(&self.options[*index]) => {
+                Some(*index)
+            }
+            _ => None,
+        }
+    }
+
+    fn selectable_option(&self) -> Option<&T> {
+        self.pointed_option
+            .and_then(|index| self.options.get(index))
+            .filter(|option| self.is_enabled(option))
+    }
+
+    fn row_background(
+        &self,
+        option: &T,
+        is_selected: bool,
+        is_hovered: bool,
+    ) -> Option<Background> {
+        if is_hovered {
+            Some(
+                self.style
+                    .hovered_background
+                    .unwrap_or(self.style.selected_background),
+            )
+        } else if is_selected {
+            Some(self.style.selected_background)
+        } else {
+            self.option_background
+                .as_ref()
+                .and_then(|option_background| option_background(option))
+        }
+    }
+
+    fn row_match(
+        &self,
+        option: &T,
+        label: &str,
+        is_selected: bool,
+    ) -> Option<(Range<usize>, Color)> {
+        if is_selected {
+            return None;
+        }
+
+        let (option_match, color) = self.option_match.as_ref()?;
+
+        option_match(option)
+            .filter(|range| {
+                range.start < range.end && label.get(range.clone()).is_some()
+            })
+            .map(|range| (range, *color))
+    }
+
+    /// Returns the color of the checkmark of the given option, if it is the
+    /// selected one.
+    fn row_checkmark(&self, option: &T) -> Option<Color> {
+        let (is_checked, color) = self.checkmark.as_ref()?;
+
+        is_checked(option).then(|| *color)
+    }
+
+    /// Returns whether the checkbox of the given option is checked, and its
+    /// color, if the options have checkboxes.
+    fn row_checkbox(&self, option: &T) -> Option<(bool, Color)> {
+        let (is_checked, color) = self.checkboxes.as_ref()?;
+
+        Some((is_checked(option), *color))
+    }
+
+    fn row_text_color(
+        &self,
+        option: &T,
+        is_selected: bool,
+        is_hovered: bool,
+    ) -> Color {
+        let color = self
+            .option_color
+            .as_ref()
+            .and_then(|option_color| option_color(option))
+            .unwrap_or(if is_hovered {
+                self.style
+                    .hovered_text_color
+                    .unwrap_or(self.style.selected_text_color)
+            } else if is_selected {
+                self.style.selected_text_color
+            } else {
+                self.style.text_color
+            });
+
+        if self.is_enabled(option) {
+            color
+        } else {
+            Color {
+                a: color.a * 0.5,
+                ..color
+            }
+        }
+    }
+}
+
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
     for List<'a, T, Renderer>
 where
@@ -302,23 +778,17 @@ where
         let limits = limits.width(Length::Fill).height(Length::Shrink);
         let text_size = self.text_size.unwrap_or(renderer.default_size());
 
-        let options_len = if self.options_empty_message.is_some()
-            && self.options.is_empty()
-        {
-            1
+        let height = if self.empty_message().is_some() {
+            f32::from(
+                self.empty_message_height
+                    .unwrap_or(text_size + self.padding.vertical()),
+            )
         } else {
-            self.options.len()
+            f32::from(text_size + self.padding.vertical())
+                * self.rows().len() as f32
         };
 
-        let size = {
-            let intrinsic = Size::new(
-                0.0,
-                f32::from(text_size + self.padding.vertical())
-                    * options_len as f32,
-            );
-
-            limits.resolve(intrinsic)
-        };
+        let size = limits.resolve(Size::new(0.0, height));
 
         layout::Node::new(size)
     }
@@ -337,26 +807,31 @@ where
                 let bounds = layout.bounds();
 
                 if bounds.contains(cursor_position) {
-                    if let Some(index) = *self.hovered_option {
-                        if let Some(option) = self.options.get(index) {
-                            *self.last_selection = Some(option.clone());
-                        }
+                    let text_size =
+                        self.text_size.unwrap_or(renderer.default_size());
+
+                    *self.pointed_option = self.hoverable_option(
+                        bounds,
+                        cursor_position,
+                        text_size,
+                    );
+
+                    if let Some(option) = self.selectable_option() {
+                        *self.last_selection = Some(option.clone());
                     }
                 }
             }
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 let bounds = layout.bounds();
 
-                if bounds.contains(cursor_position) {
+                *self.pointed_option = if bounds.contains(cursor_position) {
                     let text_size =
                         self.text_size.unwrap_or(renderer.default_size());
 
-                    *self.hovered_option = Some(
-                        ((cursor_position.y - bounds.y)
-                            / f32::from(text_size + self.padding.vertical()))
-                            as usize,
-                    );
-                }
+                    self.hoverable_option(bounds, cursor_position, text_size)
+                } else {
+                    None
+                };
             }
             Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let bounds = layout.bounds();
@@ -365,16 +840,14 @@ where
                     let text_size =
                         self.text_size.unwrap_or(renderer.default_size());
 
-                    *self.hovered_option = Some(
-                        ((cursor_position.y - bounds.y)
-                            / f32::from(text_size + self.padding.vertical()))
-                            as usize,
+                    *self.pointed_option = self.hoverable_option(
+                        bounds,
+                        cursor_position,
+                        text_size,
                     );
 
-                    if let Some(index) = *self.hovered_option {
-                        if let Some(option) = self.options.get(index) {
-                            *self.last_selection = Some(option.clone());
-                        }
+                    if let Some(option) = self.selectable_option() {
+                        *self.last_selection = Some(option.clone());
                     }
                 }
             }
@@ -405,7 +878,7 @@ where
         renderer: &mut Renderer,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        cursor_position: Point,
         viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
@@ -413,25 +886,90 @@ where
         let text_size = self.text_size.unwrap_or(renderer.default_size());
         let option_height = (text_size + self.padding.vertical()) as usize;
 
+        // The keyboard highlight follows `hovered_option`, while the option
+        // under the mouse is styled on its own
+        let pointed_option = if bounds.contains(cursor_position) {
+            self.hoverable_option(bounds, cursor_position, text_size)
+        } else {
+            None
+        };
+
+        if let Some(message) = self.empty_message() {
+            let (x, horizontal_alignment) = if self.center_empty_message {
+                (bounds.center_x(), alignment::Horizontal::Center)
+            } else {
+                self.row_start(bounds)
+            };
+
+            renderer.fill_text(Text {
+                content: message,
+                bounds: Rectangle {
+                    x,
+                    y: bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                size: f32::from(text_size),
+                font: self.font.clone(),
+                color: self
+                    .empty_message_color
+                    .unwrap_or(self.style.text_color),
+                horizontal_alignment,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+
+            return;
+        }
+
         let offset = viewport.y - bounds.y;
         let start = (offset / option_height as f32) as usize;
         let end =
             ((offset + viewport.height) / option_height as f32).ceil() as usize;
 
-        let visible_options = &self.options[start..end.min(self.options.len())];
-
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
-            let is_selected = *self.hovered_option == Some(i);
+        let rows = self.rows();
+        let visible_rows = &rows[start.min(rows.len())..end.min(rows.len())];
 
+        for (row, kind) in visible_rows.iter().enumerate() {
             let bounds = Rectangle {
                 x: bounds.x,
-                y: bounds.y + (option_height * i) as f32,
+                y: bounds.y + (option_height * (start + row)) as f32,
                 width: bounds.width,
                 height: f32::from(text_size + self.padding.vertical()),
             };
 
-            if is_selected {
+            let i = match kind {
+                Row::Header(group) => {
+                    let (x, horizontal_alignment) = self.row_start(bounds);
+
+                    renderer.fill_text(Text {
+                        content: group,
+                        bounds: Rectangle {
+                            x,
+                            y: bounds.center_y(),
+                            width: f32::INFINITY,
+                            ..bounds
+                        },
+                        size: f32::from(text_size),
+                        font: self.font.clone(),
+                        color: self
+                            .group_header_color
+                            .unwrap_or(self.style.text_color),
+                        horizontal_alignment,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+
+                    continue;
+                }
+                Row::Option(index) => *index,
+            };
+
+            let option = &self.options[i];
+            let is_selected = *self.hovered_option == Some(i);
+            let is_hovered = pointed_option == Some(i);
+
+            if let Some(background) =
+                self.row_background(option, is_selected, is_hovered)
+            {
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds,
@@ -439,28 +977,145 @@ where
                         border_width: 0.0,
                         border_radius: 0.0,
                     },
-                    self.style.selected_background,
+                    background,
                 );
             }
 
-            renderer.fill_text(Text {
-                content: &option.to_string(),
-                bounds: Rectangle {
-                    x: bounds.x + self.padding.left as f32,
-                    y: bounds.center_y(),
-                    width: f32::INFINITY,
-                    ..bounds
-                },
-                size: f32::from(text_size),
-                font: self.font.clone(),
-                color: if is_selected {
-                    self.style.selected_text_color
-                } else {
-                    self.style.text_color
-                },
-                horizontal_alignment: alignment::Horizontal::Left,
-                vertical_alignment: alignment::Vertical::Center,
-            });
+            let color = self.row_text_color(option, is_selected, is_hovered);
+            let label = option.to_string();
+
+            let segments =
+                match self.row_match(option, &label, is_selected || is_hovered)
+                {
+                    Some((range, match_color)) => vec![
+                        (&label[..range.start], color),
+                        (&label[range.clone()], match_color),
+                        (&label[range.end..], color),
+                    ],
+                    None => vec![(&label[..], color)],
+                };
+
+            // Rows are mirrored by stepping from their start towards their
+            // end, which is leftwards when laid out from right to left
+            let step = if self.right_to_left { -1.0 } else { 1.0 };
+            let (mut x, _) = self.row_start(bounds);
+
+            if let Some((is_checked, checkbox_color)) =
+                self.row_checkbox(option)
+            {
+                let size = f32::from(text_size) * 0.75;
+
+                let checkbox_bounds = Rectangle {
+                    x: if self.right_to_left { x - size } else { x },
+                    y: bounds.center_y() - size / 2.0,
+                    width: size,
+                    height: size,
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: checkbox_bounds,
+                        border_color: checkbox_color,
+                        border_width: 1.0,
+                        border_radius: 2.0,
+                    },
+                    Color::TRANSPARENT,
+                );
+
+                if is_checked {
+                    renderer.fill_text(Text {
+                        content: &Renderer::CHECKMARK_ICON.to_string(),
+                        bounds: Rectangle {
+                            x: checkbox_bounds.center_x(),
+                            y: checkbox_bounds.center_y(),
+                            ..checkbox_bounds
+                        },
+                        size: size * 0.7,
+                        font: Renderer::ICON_FONT,
+                        color: checkbox_color,
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
+
+                x += step * f32::from(text_size);
+            }
+
+            if self.right_to_left {
+                x -= segments
+                    .iter()
+                    .map(|(content, _)| {
+                        renderer.measure_width(
+                            content,
+                            text_size,
+                            self.font.clone(),
+                        )
+                    })
+                    .sum::<f32>();
+            }
+
+            for (content, color) in segments {
+                if content.is_empty() {
+                    continue;
+                }
+
+                renderer.fill_text(Text {
+                    content,
+                    bounds: Rectangle {
+                        x,
+                        y: bounds.center_y(),
+                        width: f32::INFINITY,
+                        ..bounds
+                    },
+                    size: f32::from(text_size),
+                    font: self.font.clone(),
+                    color,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+
+                x += renderer.measure_width(
+                    content,
+                    text_size,
+                    self.font.clone(),
+                );
+            }
+
+            let (mut end, end_alignment) = self.row_end(bounds);
+
+            if let Some(checkmark_color) = self.row_checkmark(option) {
+                renderer.fill_text(Text {
+                    content: &Renderer::CHECKMARK_ICON.to_string(),
+                    bounds: Rectangle {
+                        x: end,
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    size: f32::from(text_size) * 0.75,
+                    font: Renderer::ICON_FONT,
+                    color: checkmark_color,
+                    horizontal_alignment: end_alignment,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+
+                end -= step * f32::from(text_size);
+            }
+
+            if let Some(trailing_label) = &self.trailing_label {
+                renderer.fill_text(Text {
+                    content: &trailing_label(option),
+                    bounds: Rectangle {
+                        x: end,
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    size: f32::from(text_size) * 0.75,
+                    font: self.font.clone(),
+                    color,
+                    horizontal_alignment: end_alignment,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+            }
         }
     }
 }
@@ -476,3 +1131,480 @@ where
         Element::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    /// Owns the state a [`List`] borrows while it handles events.
+    #[derive(Default)]
+    struct Fixture {
+        hovered_option: Option<usize>,
+        pointed_option: Option<usize>,
+        last_selection: Option<&'static str>,
+    }
+
+    impl Fixture {
+        /// Returns a [`List`] of the given options with the default
+        /// appearance.
+        fn list<'a>(
+            &'a mut self,
+            options: &'a [&'static str],
+        ) -> List<'a, &'static str, Null> {
+            List {
+                options,
+                options_empty_message: &None,
+                hovered_option: &mut self.hovered_option,
+                pointed_option: &mut self.pointed_option,
+                last_selection: &mut self.last_selection,
+                padding: Padding::ZERO,
+                text_size: None,
+                font: Default::default(),
+                trailing_label: None,
+                option_background: None,
+                option_color: None,
+                option_match: None,
+                option_enabled: None,
+                group_by: None,
+                group_header_color: None,
+                checkmark: None,
+                checkboxes: None,
+                empty_message_height: None,
+                center_empty_message: false,
+                empty_message_color: None,
+                right_to_left: false,
+                style: Style::default(),
+            }
+        }
+    }
+
+    #[test]
+    fn empty_message_uses_fixed_height() {
+        let options: [&str; 0] = [];
+        let message = Some(String::from("No results"));
+
+        let mut fixture = Fixture::default();
+        let list = List {
+            options_empty_message: &message,
+            empty_message_height: Some(60),
+            center_empty_message: true,
+            ..fixture.list(&options)
+        };
+
+        let node = Widget::<(), Null>::layout(
+            &list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        assert_eq!(node.size(), Size::new(200.0, 60.0));
+    }
+
+    #[test]
+    fn option_background_tints_rows_that_are_not_hovered() {
+        let options = ["Apple", "Banana"];
+        let tint = Background::Color(Color::from_rgb(1.0, 1.0, 0.0));
+
+        let mut fixture = Fixture::default();
+        let list = List {
+            option_background: Some(Box::new(move |option: &&str| {
+                option.contains("an").then(|| tint)
+            })),
+            ..fixture.list(&options)
+        };
+
+        assert_eq!(list.row_background(&"Apple", false, false), None);
+        assert_eq!(list.row_background(&"Banana", false, false), Some(tint));
+        assert_eq!(
+            list.row_background(&"Banana", true, false),
+            Some(Style::default().selected_background)
+        );
+    }
+
+    #[test]
+    fn option_color_tints_row_labels() {
+        let options = ["Online", "Offline"];
+        let green = Color::from_rgb(0.0, 1.0, 0.0);
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+
+        let mut fixture = Fixture::default();
+        let list = List {
+            option_color: Some(Box::new(move |option: &&str| match *option {
+                "Online" => Some(green),
+                "Offline" => Some(red),
+                _ => None,
+            })),
+            ..fixture.list(&options)
+        };
+
+        assert_eq!(list.row_text_color(&"Online", false, false), green);
+        assert_eq!(list.row_text_color(&"Offline", true, false), red);
+        assert_eq!(
+            list.row_text_color(&"Away", false, false),
+            Style::default().text_color
+        );
+    }
+
+    #[test]
+    fn max_height_limits_the_overlay() {
+        let options: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        let mut state = State::new();
+        let mut hovered_option = None;
+        let mut last_selection = None;
+
+        let menu = Menu::<_, Null>::new(
+            &mut state,
+            &options,
+            &None,
+            &mut hovered_option,
+            &mut last_selection,
+        )
+        .width(200)
+        .max_height(150);
+
+        let overlay = Overlay::<(), Null>::new(menu, 20.0);
+        let node = crate::Overlay::layout(
+            &overlay,
+            &Null,
+            Size::new(800.0, 3000.0),
+            Point::ORIGIN,
+        );
+
+        assert_eq!(node.size().height, 150.0);
+    }
+
+    fn overlay_position(options: &[String], max_height: Option<u16>) -> Point {
+        let mut state = State::new();
+        let mut hovered_option = None;
+        let mut last_selection = None;
+
+        let mut menu = Menu::<_, Null>::new(
+            &mut state,
+            options,
+            &None,
+            &mut hovered_option,
+            &mut last_selection,
+        )
+        .width(200);
+
+        if let Some(max_height) = max_height {
+            menu = menu.max_height(max_height);
+        }
+
+        let overlay = Overlay::<(), Null>::new(menu, 20.0);
+        let node = crate::Overlay::layout(
+            &overlay,
+            &Null,
+            Size::new(800.0, 600.0),
+            Point::new(0.0, 400.0),
+        );
+
+        node.bounds().position()
+    }
+
+    #[test]
+    fn menu_stays_below_when_it_fits() {
+        let options: Vec<String> = (0..3).map(|i| i.to_string()).collect();
+
+        assert_eq!(overlay_position(&options, None), Point::new(0.0, 420.0));
+    }
+
+    #[test]
+    fn menu_flips_above_when_it_does_not_fit() {
+        let options: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+
+        assert_eq!(overlay_position(&options, None), Point::new(0.0, 0.0));
+        assert_eq!(
+            overlay_position(&options, Some(300)),
+            Point::new(0.0, 100.0)
+        );
+        assert_eq!(
+            overlay_position(&options, Some(150)),
+            Point::new(0.0, 420.0)
+        );
+    }
+
+    #[test]
+    fn option_match_emphasizes_rows_that_are_not_hovered() {
+        let options = ["Apple", "Banana"];
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+
+        let mut fixture = Fixture::default();
+        let list = List {
+            option_match: Some((
+                Box::new(|option: &&str| option.find("an").map(|i| i..i + 2)),
+                red,
+            )),
+            ..fixture.list(&options)
+        };
+
+        assert_eq!(list.row_match(&"Apple", "Apple", false), None);
+        assert_eq!(
+            list.row_match(&"Banana", "Banana", false),
+            Some((1..3, red))
+        );
+        assert_eq!(list.row_match(&"Banana", "Banana", true), None);
+    }
+
+    #[test]
+    fn disabled_options_cannot_be_hovered_nor_selected() {
+        let options = ["Apple", "Banana"];
+
+        let mut fixture = Fixture::default();
+        let mut list = List {
+            text_size: Some(20),
+            option_enabled: Some(Box::new(|option: &&str| *option != "Banana")),
+            ..fixture.list(&options)
+        };
+
+        let color = Style::default().text_color;
+
+        assert_eq!(list.row_text_color(&"Apple", false, false), color);
+        assert_eq!(
+            list.row_text_color(&"Banana", false, false),
+            Color {
+                a: color.a * 0.5,
+                ..color
+            }
+        );
+
+        let node = layout::Node::new(Size::new(200.0, 40.0));
+        let mut messages: Vec<()> = Vec::new();
+
+        for position in [Point::new(10.0, 10.0), Point::new(10.0, 30.0)] {
+            for event in [
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            ] {
+                let _ = Widget::<(), Null>::on_event(
+                    &mut list,
+                    event,
+                    Layout::new(&node),
+                    position,
+                    &Null,
+                    &mut crate::clipboard::Null,
+                    &mut Shell::new(&mut messages),
+                );
+            }
+        }
+
+        assert_eq!(*list.pointed_option, None);
+        assert_eq!(*list.hovered_option, None);
+        assert_eq!(*list.last_selection, Some("Apple"));
+    }
+
+    #[test]
+    fn group_headers_are_not_hoverable() {
+        let options = ["Apple", "Avocado", "Banana"];
+
+        let mut fixture = Fixture::default();
+        let list = List {
+            text_size: Some(20),
+            group_by: Some(Box::new(|option: &&str| {
+                Cow::Borrowed(&option[..1])
+            })),
+            ..fixture.list(&options)
+        };
+
+        let node = Widget::<(), Null>::layout(
+            &list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        assert_eq!(node.size(), Size::new(200.0, 100.0));
+
+        let bounds = node.bounds();
+        let hovered =
+            |y| list.hoverable_option(bounds, Point::new(10.0, y), 20);
+
+        assert_eq!(hovered(10.0), None);
+        assert_eq!(hovered(30.0), Some(0));
+        assert_eq!(hovered(50.0), Some(1));
+        assert_eq!(hovered(70.0), None);
+        assert_eq!(hovered(90.0), Some(2));
+    }
+
+    #[test]
+    fn checkmark_is_drawn_on_the_selected_option() {
+        let options = ["Apple", "Banana"];
+        let green = Color::from_rgb(0.0, 1.0, 0.0);
+
+        let mut fixture = Fixture::default();
+        let list = List {
+            checkmark: Some((
+                Box::new(|option: &&str| *option == "Banana"),
+                green,
+            )),
+            ..fixture.list(&options)
+        };
+
+        assert_eq!(list.row_checkmark(&"Apple"), None);
+        assert_eq!(list.row_checkmark(&"Banana"), Some(green));
+    }
+
+    #[test]
+    fn scrollbar_can_be_grabbed_without_selecting_an_option() {
+        let options: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        let mut state = State::new();
+        let mut hovered_option = None;
+        let mut last_selection = None;
+
+        let menu = Menu::<_, Null>::new(
+            &mut state,
+            &options,
+            &None,
+            &mut hovered_option,
+            &mut last_selection,
+        )
+        .width(200)
+        .max_height(150)
+        .style(Style {
+            scrollbar_width: 20,
+            scroller_width: 20,
+            ..Style::default()
+        });
+
+        let mut overlay = Overlay::<(), Null>::new(menu, 20.0);
+        let node = crate::Overlay::layout(
+            &overlay,
+            &Null,
+            Size::new(800.0, 3000.0),
+            Point::ORIGIN,
+        );
+
+        let position = Point::new(182.0, 30.0);
+        let mut messages: Vec<()> = Vec::new();
+
+        let status = crate::Overlay::on_event(
+            &mut overlay,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            position,
+            &Null,
+            &mut crate::clipboard::Null,
+            &mut Shell::new(&mut messages),
+        );
+
+        drop(overlay);
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(state.scrollable.is_scroller_grabbed());
+        assert_eq!(last_selection, None);
+    }
+
+    #[test]
+    fn checkboxes_reflect_the_checked_options() {
+        let options = ["Apple", "Banana"];
+        let blue = Color::from_rgb(0.0, 0.0, 1.0);
+
+        let mut fixture = Fixture::default();
+        let list = List {
+            checkboxes: Some((
+                Box::new(|option: &&str| *option == "Banana"),
+                blue,
+            )),
+            ..fixture.list(&options)
+        };
+
+        assert_eq!(list.row_checkbox(&"Apple"), Some((false, blue)));
+        assert_eq!(list.row_checkbox(&"Banana"), Some((true, blue)));
+    }
+
+    #[test]
+    fn hovered_rows_are_styled_apart_from_the_keyboard_highlight() {
+        let options = ["Apple", "Banana"];
+        let hovered = Background::Color(Color::from_rgb(0.0, 1.0, 0.0));
+        let white = Color::WHITE;
+
+        let mut fixture = Fixture::default();
+        let list = List {
+            style: Style {
+                hovered_background: Some(hovered),
+                hovered_text_color: Some(Color::BLACK),
+                ..Style::default()
+            },
+            ..fixture.list(&options)
+        };
+
+        assert_eq!(
+            list.row_background(&"Apple", true, false),
+            Some(Style::default().selected_background)
+        );
+        assert_eq!(list.row_background(&"Apple", true, true), Some(hovered));
+        assert_eq!(list.row_background(&"Apple", false, true), Some(hovered));
+        assert_eq!(list.row_text_color(&"Banana", true, false), white);
+        assert_eq!(list.row_text_color(&"Banana", false, true), Color::BLACK);
+    }
+
+    #[test]
+    fn right_to_left_mirrors_the_rows() {
+        let options = ["Apple"];
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 20.0,
+        };
+
+        let mut fixture = Fixture::default();
+        let mut list = List {
+            padding: Padding {
+                top: 0,
+                right: 5,
+                bottom: 0,
+                left: 10,
+            },
+            ..fixture.list(&options)
+        };
+
+        assert_eq!(list.row_start(bounds), (10.0, alignment::Horizontal::Left));
+        assert_eq!(list.row_end(bounds), (95.0, alignment::Horizontal::Right));
+
+        list.right_to_left = true;
+
+        assert_eq!(
+            list.row_start(bounds),
+            (95.0, alignment::Horizontal::Right)
+        );
+        assert_eq!(list.row_end(bounds), (10.0, alignment::Horizontal::Left));
+    }
+
+    #[test]
+    fn moving_the_mouse_keeps_the_keyboard_highlight() {
+        let options = ["Apple", "Banana"];
+
+        let mut fixture = Fixture {
+            hovered_option: Some(0),
+            ..Fixture::default()
+        };
+        let mut list = List {
+            text_size: Some(20),
+            ..fixture.list(&options)
+        };
+
+        let node = layout::Node::new(Size::new(200.0, 40.0));
+        let position = Point::new(10.0, 30.0);
+        let mut messages: Vec<()> = Vec::new();
+
+        for event in [
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        ] {
+            let _ = Widget::<(), Null>::on_event(
+                &mut list,
+                event,
+                Layout::new(&node),
+                position,
+                &Null,
+                &mut crate::clipboard::Null,
+                &mut Shell::new(&mut messages),
+            );
+        }
+
+        assert_eq!(*list.hovered_option, Some(0));
+        assert_eq!(*list.pointed_option, Some(1));
+        assert_eq!(*list.last_selection, Some("Banana"));
+    }
+}
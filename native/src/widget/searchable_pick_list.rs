@@ -2,6 +2,8 @@
 //!
 //! A [`SearchablePickList`] has some local [`State`].
 use std::borrow::Cow;
+use std::collections::HashSet;
+use std::ops::Range;
 
 use crate::alignment;
 use crate::event::{self, Event};
@@ -9,45 +11,72 @@ use crate::keyboard;
 use crate::layout;
 use crate::mouse::{self, click};
 use crate::overlay;
-use crate::overlay::menu::Menu;
+use crate::overlay::menu::{self, Menu};
 use crate::renderer;
 use crate::text::{self, Text};
+use crate::time::{Duration, Instant};
 use crate::touch;
 use crate::widget::pick_list;
 use crate::widget::text_input_shared;
 use crate::widget::text_input_shared::cursor;
 use crate::widget::text_input_shared::cursor::Cursor;
 use crate::widget::text_input_shared::editor::Editor;
+use crate::widget::text_input_shared::history::History;
 use crate::widget::text_input_shared::value::Value;
 use crate::{
-    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell, Size,
-    Widget
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Vector, Widget,
 };
 
 pub use iced_style::searchable_pick_list::StyleSheet;
 
+/// The frames of the spinner displayed while a [`SearchablePickList`] is
+/// loading.
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
 
+/// The time each frame of the spinner is displayed.
+const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The time the caret is displayed, and then hidden, while blinking.
+const CARET_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The idle time after which the characters typed ahead are forgotten.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The space between the field and the helper or error text below it.
+const MESSAGE_SPACING: f32 = 4.0;
+
+/// The width of the area reserved for the arrow icon, excluding padding.
+const ARROW_WIDTH: f32 = 30.0;
+
+/// The width of the area reserved for the clear icon.
+const CLEAR_ICON_WIDTH: f32 = 20.0;
 
 /// A field that can be filled with text.
 ///
 /// # Example
 /// ```
-/// # use iced_native::{text_input_shared, renderer::Null};
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::searchable_pick_list;
 /// #
-/// # pub type SearchablePickList<'a, Message> = iced_native::SearchablePickList<'a, Message, Null>;
+/// # pub type SearchablePickList<'a, T, Message> = iced_native::widget::SearchablePickList<'a, T, Message, Null>;
 /// #[derive(Debug, Clone)]
 /// enum Message {
-///     SearchablePickListChanged(String),
+///     SearchChanged(String),
+///     FruitSelected(&'static str),
 /// }
 ///
-/// let mut state = text_input_shared::State::new();
-/// let value = "Some text";
+/// let mut state = searchable_pick_list::State::new();
+/// let fruits = ["Apple", "Banana", "Cherry"];
 ///
 /// let input = SearchablePickList::new(
 ///     &mut state,
-///     "This is the placeholder...",
-///     value,
-///     Message::SearchablePickListChanged,
+///     "Search a fruit...",
+///     "",
+///     None,
+///     &fruits[..],
+///     Message::SearchChanged,
+///     Message::FruitSelected,
 /// )
 /// .padding(10);
 /// ```
@@ -60,6 +89,10 @@ where
     state: &'a mut State<T>,
     // Text Input
     placeholder: String,
+    placeholder_focused: Option<String>,
+    disabled_placeholder: Option<String>,
+    prefix: Option<String>,
+    suffix: Option<String>,
     value: Value,
     font: Renderer::Font,
     width: Length,
@@ -67,13 +100,77 @@ where
     padding: Padding,
     size: Option<u16>,
     on_change: Box<dyn Fn(String) -> Message>,
+    debounce: Duration,
     on_submit: Option<Message>,
+    on_focus: Option<Message>,
+    on_unfocus: Option<Message>,
+    on_key: Option<
+        Box<dyn Fn(keyboard::KeyCode, keyboard::Modifiers) -> Option<Message>>,
+    >,
     select_all_first_click: bool,
+    whole_line_on_no_selection: bool,
+    selection_opacity: f32,
+    caret_blink: bool,
+    underline: bool,
+    wrap: bool,
+    arrow_reserved_width: f32,
+    drag_threshold: f32,
+    editable: bool,
+    max_length: Option<usize>,
+    is_enabled: bool,
+    is_read_only: bool,
+    is_secure: bool,
+    helper_text: Option<String>,
+    error_text: Option<String>,
     // Pick List
     options: Cow<'a, [T]>,
     options_empty_message: Option<String>,
+    menu_font: Option<Renderer::Font>,
+    menu_text_size: Option<u16>,
+    options_empty_message_height: Option<u16>,
+    center_options_empty_message: bool,
     selected: Option<T>,
     on_selected: Box<dyn Fn(T) -> Message>,
+    allow_custom: bool,
+    on_custom: Option<Box<dyn Fn(String) -> Message>>,
+    on_clear: Option<Message>,
+    on_open: Option<Message>,
+    on_close: Option<Message>,
+    on_hover_option: Option<Box<dyn Fn(T) -> Message>>,
+    hover_debounce: Duration,
+    on_filtered: Option<Box<dyn Fn(usize) -> Message>>,
+    multi_select: bool,
+    on_toggle: Option<Box<dyn Fn(T, bool) -> Message>>,
+    defer_selection_until_close: bool,
+    on_selections: Option<Box<dyn Fn(Vec<T>) -> Message>>,
+    option_color: Option<Box<dyn Fn(&T) -> Option<Color>>>,
+    option_enabled: Option<Box<dyn Fn(&T) -> bool>>,
+    group_by: Option<Box<dyn Fn(&T) -> Cow<'_, str>>>,
+    collapsed_alignment: alignment::Horizontal,
+    text_alignment: alignment::Horizontal,
+    layout_direction: LayoutDirection,
+    prehover_selected: bool,
+    menu_max_height: Option<u16>,
+    menu_max_rows: Option<usize>,
+    menu_min_width: u16,
+    open_on_hover: bool,
+    hover_open_delay: Duration,
+    ignored_outside_clicks: Vec<Rectangle>,
+    loading: bool,
+    loading_since: Option<Instant>,
+    loading_message: Option<String>,
+    icon: Option<(char, Renderer::Font)>,
+    auto_select_single_match: bool,
+    type_ahead: bool,
+    dedup: bool,
+    wheel_cycle: bool,
+    show_full_on_hover: bool,
+    sync_text_on_select: bool,
+    skip_if_unchanged: bool,
+    restore_on_escape: bool,
+    min_search_length: usize,
+    match_highlight_mode: MatchHighlightMode,
+    match_score: Option<Box<dyn Fn(&T, &str) -> f32>>,
     // Style
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
@@ -104,29 +201,150 @@ where
     where
         F: 'static + Fn(String) -> Message,
     {
+        // Text that has not been reported yet takes precedence
+        let value = match &state.pending_change {
+            Some((pending, _)) => pending.clone(),
+            None => Value::new(value),
+        };
+
+        state.value = value.clone();
+
+        // The spinner keeps its phase only while `loading` is set again
+        let loading_since = state.loading_since.take();
+
         SearchablePickList {
             state,
             // Text Input
             placeholder: String::from(placeholder),
-            value: Value::new(value),
+            placeholder_focused: None,
+            disabled_placeholder: None,
+            prefix: None,
+            suffix: None,
+            value,
             font: Default::default(),
             width: Length::Fill,
             max_width: u32::MAX,
             padding: Padding::ZERO,
             size: None,
             on_change: Box::new(on_change),
+            debounce: Duration::ZERO,
             on_submit: None,
+            on_focus: None,
+            on_unfocus: None,
+            on_key: None,
             select_all_first_click: false,
+            whole_line_on_no_selection: false,
+            selection_opacity: 1.0,
+            caret_blink: true,
+            underline: false,
+            wrap: false,
+            arrow_reserved_width: ARROW_WIDTH,
+            drag_threshold: 3.0,
+            editable: true,
+            max_length: None,
+            is_enabled: true,
+            is_read_only: false,
+            is_secure: false,
+            helper_text: None,
+            error_text: None,
             // Pick List
             options: options.into(),
             options_empty_message: None,
+            menu_font: None,
+            menu_text_size: None,
+            options_empty_message_height: None,
+            center_options_empty_message: false,
             selected,
             on_selected: Box::new(on_selected),
+            allow_custom: false,
+            on_custom: None,
+            on_clear: None,
+            on_open: None,
+            on_close: None,
+            on_hover_option: None,
+            hover_debounce: Duration::ZERO,
+            on_filtered: None,
+            multi_select: false,
+            on_toggle: None,
+            defer_selection_until_close: false,
+            on_selections: None,
+            option_color: None,
+            option_enabled: None,
+            group_by: None,
+            collapsed_alignment: alignment::Horizontal::Left,
+            text_alignment: alignment::Horizontal::Left,
+            layout_direction: LayoutDirection::LeftToRight,
+            prehover_selected: true,
+            menu_max_height: None,
+            menu_max_rows: None,
+            menu_min_width: 0,
+            open_on_hover: false,
+            hover_open_delay: Duration::ZERO,
+            ignored_outside_clicks: Vec::new(),
+            loading: false,
+            loading_since,
+            loading_message: Some(String::from("Loading…")),
+            icon: None,
+            auto_select_single_match: false,
+            type_ahead: false,
+            dedup: false,
+            wheel_cycle: false,
+            show_full_on_hover: false,
+            sync_text_on_select: true,
+            skip_if_unchanged: false,
+            restore_on_escape: false,
+            min_search_length: 0,
+            match_highlight_mode: MatchHighlightMode::Span,
+            match_score: None,
             // Style
             style_sheet: Default::default(),
         }
     }
 
+    /// Replaces the options of the [`SearchablePickList`] with the ones
+    /// produced by the given iterator.
+    ///
+    /// While the menu is filtered, only the options matching the text of the
+    /// [`SearchablePickList`] are collected, which avoids allocating every
+    /// candidate of large or lazy sources. The options are collected again
+    /// every time the [`SearchablePickList`] is built.
+    pub fn options_iter(mut self, options: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Clone,
+    {
+        let options = options.into_iter();
+        let query = self.value.to_string();
+
+        if self.state.filtered_indices.is_some() && !query.is_empty() {
+            self.options = Cow::Owned(
+                options
+                    .filter(|option| matches_query(option, &query))
+                    .collect(),
+            );
+
+            self.filter_options();
+        } else {
+            self.options = Cow::Owned(options.collect());
+        }
+
+        self
+    }
+
+    /// Selects the option at the given index of the options of the
+    /// [`SearchablePickList`].
+    ///
+    /// It overrides the option selected in [`new`](Self::new), so `None` or
+    /// an index out of bounds leaves no option selected. The selected option
+    /// is hovered and scrolled into view when the menu opens.
+    pub fn selected_index(mut self, index: Option<usize>) -> Self
+    where
+        T: Clone,
+    {
+        self.selected =
+            index.and_then(|index| self.options.get(index)).cloned();
+        self
+    }
+
     /// Sets the [`Font`] of the [`Text`].
     ///
     /// [`Font`]: crate::widget::text::Renderer::Font
@@ -159,13 +377,66 @@ where
         self
     }
 
+    /// Sets the time the text of the [`SearchablePickList`] needs to stay
+    /// unchanged before the `on_change` message is produced.
+    ///
+    /// Rapid edits are coalesced into a single message, which is produced the
+    /// next time the [`SearchablePickList`] receives an event after the
+    /// duration elapses, like the pointer moving. Until then, the menu is
+    /// filtered with the edited text, but anything the application derives
+    /// from the message, like fetching new options, is delayed.
+    ///
+    /// When the user stops interacting, the last edit is only delivered by
+    /// [`State::poll`], which the application should call while
+    /// [`State::needs_tick`] returns `true`.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
     /// Sets the message that should be produced when the [`SearchablePickList`] is
     /// focused and the enter key is pressed.
+    ///
+    /// It commits the typed text as is. If an option of the menu is
+    /// highlighted, pressing enter selects it through `on_selected` instead,
+    /// and a custom value is committed through [`on_custom`](Self::on_custom)
+    /// when allowed.
     pub fn on_submit(mut self, message: Message) -> Self {
         self.on_submit = Some(message);
         self
     }
 
+    /// Sets the message that should be produced when the
+    /// [`SearchablePickList`] gains focus.
+    pub fn on_focus(mut self, message: Message) -> Self {
+        self.on_focus = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the
+    /// [`SearchablePickList`] loses focus, like when clicking outside of it
+    /// or pressing `Escape`.
+    ///
+    /// Focus changes made through the [`State`] are not reported.
+    pub fn on_unfocus(mut self, message: Message) -> Self {
+        self.on_unfocus = Some(message);
+        self
+    }
+
+    /// Sets the function that will be consulted whenever a key is pressed
+    /// while the [`SearchablePickList`] is focused.
+    ///
+    /// If it produces a message, the message is published and the default
+    /// handling of the key is skipped.
+    pub fn on_key(
+        mut self,
+        on_key: impl Fn(keyboard::KeyCode, keyboard::Modifiers) -> Option<Message>
+            + 'static,
+    ) -> Self {
+        self.on_key = Some(Box::new(on_key));
+        self
+    }
+
     /// Sets the style of the [`SearchablePickList`].
     pub fn style(
         mut self,
@@ -181,963 +452,7518 @@ where
         self
     }
 
-    /// Returns the current [`State`] of the [`SearchablePickList`].
-    pub fn state(&self) -> &State<T> {
-        self.state
+    /// Sets whether copying or cutting without a selection should copy or
+    /// cut the whole text of the [`SearchablePickList`].
+    ///
+    /// Nothing is copied without a selection by default.
+    pub fn whole_line_on_no_selection(mut self, whole_line: bool) -> Self {
+        self.whole_line_on_no_selection = whole_line;
+        self
     }
 
-    /// Sets the message to show if the options list of the [`SearchablePickList`] is empty.
-    pub fn options_empty_message(mut self, message: String) -> Self {
-        self.options_empty_message = Some(message);
+    /// Sets the opacity of the selection highlight of the [`SearchablePickList`].
+    ///
+    /// The opacity is clamped to the `[0.0, 1.0]` range and multiplies the
+    /// alpha of the selection color of the [`StyleSheet`]. It is `1.0` by
+    /// default.
+    pub fn selection_opacity(mut self, opacity: f32) -> Self {
+        self.selection_opacity = opacity.max(0.0).min(1.0);
         self
     }
-}
 
-impl<'a, T, Message, Renderer> SearchablePickList<'a, T, Message, Renderer>
-where
-    T: Clone + ToString + Eq,
-    [T]: ToOwned<Owned = Vec<T>>,
-    Renderer: text::Renderer,
-{
-    /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
-    /// [`Value`] if provided.
-    pub fn draw(
-        &self,
-        renderer: &mut Renderer,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        value: Option<&Value>,
-    ) {
-        let value = value.unwrap_or(&self.value);
-        let bounds = layout.bounds();
-        let text_bounds = layout.children().next().unwrap().bounds();
+    /// Sets whether the caret of the [`SearchablePickList`] should blink.
+    ///
+    /// The caret is displayed right after every edit, and it blinks as the
+    /// [`SearchablePickList`] is redrawn. While it is focused,
+    /// [`State::needs_tick`] returns `true`, so the application can keep it
+    /// redrawing. Disabling it keeps the caret always displayed, which is
+    /// useful for deterministic screenshots. It is enabled by default.
+    pub fn caret_blink(mut self, blink: bool) -> Self {
+        self.caret_blink = blink;
+        self
+    }
 
-        draw(
-            renderer,
-            bounds,
-            text_bounds,
-            cursor_position,
-            self.state.pick_list.is_open,
-            self.selected.as_ref(),
-            &self.font,
-            self.size,
-            &self.placeholder,
-            self.padding,
-            value,
-            self.state.is_focused,
-            self.state.cursor,
-            self.style_sheet.as_ref(),
-        )
+    /// Sets whether the [`SearchablePickList`] should only draw a line at its
+    /// bottom edge instead of a full border.
+    ///
+    /// The line is thicker while the [`SearchablePickList`] is focused.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
     }
-}
 
-impl<'a, T, Message, Renderer> Widget<Message, Renderer>
-    for SearchablePickList<'a, T, Message, Renderer>
-where
-    T: Clone + ToString + Eq,
-    [T]: ToOwned<Owned = Vec<T>>,
-    Message: Clone,
-    Renderer: text::Renderer + 'a,
-{
-    fn width(&self) -> Length {
-        self.width
+    /// Sets whether the label of the closed [`SearchablePickList`] should
+    /// wrap into multiple lines instead of being truncated.
+    ///
+    /// The [`SearchablePickList`] grows vertically to fit the wrapped label,
+    /// while its text remains in a single line when it is open.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
     }
 
-    fn height(&self) -> Length {
-        Length::Shrink
+    /// Sets the width in pixels reserved for the arrow icon of the
+    /// [`SearchablePickList`], between its text and its right padding.
+    ///
+    /// It is `30.0` by default.
+    pub fn arrow_reserved_width(mut self, width: f32) -> Self {
+        self.arrow_reserved_width = width.max(0.0);
+        self
     }
 
-    fn layout(
-        &self,
-        renderer: &Renderer,
-        limits: &layout::Limits,
-    ) -> layout::Node {
-        let text_size = self.size.unwrap_or(renderer.default_size());
+    /// Sets the placeholder displayed while the [`SearchablePickList`] is
+    /// focused, like a hint to type in order to search.
+    ///
+    /// The placeholder given to [`new`](Self::new) is displayed otherwise,
+    /// and also while focused if this is not set.
+    pub fn placeholder_focused(mut self, placeholder: String) -> Self {
+        self.placeholder_focused = Some(placeholder);
+        self
+    }
 
-        let limits = limits
-            .pad(self.padding)
-            .width(self.width)
-            .max_width(self.max_width)
-            .height(Length::Units(text_size));
+    /// Sets the placeholder displayed while the [`SearchablePickList`] is
+    /// disabled, like "Unavailable".
+    ///
+    /// It is drawn with the disabled text color instead of the placeholder
+    /// color, since it describes the state of the field rather than hinting
+    /// at what to type.
+    pub fn disabled_placeholder(mut self, placeholder: String) -> Self {
+        self.disabled_placeholder = Some(placeholder);
+        self
+    }
 
-        let mut text = layout::Node::new(limits.resolve(Size::ZERO));
-        text.move_to(Point::new(
-            self.padding.left.into(),
-            self.padding.top.into(),
-        ));
+    /// Sets a static text displayed inside the [`SearchablePickList`], right
+    /// before its text, like a currency symbol or a scheme.
+    ///
+    /// It is drawn with the placeholder color and cannot be edited.
+    pub fn prefix(mut self, prefix: String) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
 
-        layout::Node::with_children(text.size().pad(self.padding), vec![text])
+    /// Sets a static text displayed inside the [`SearchablePickList`], pinned
+    /// right before its arrow, like a unit.
+    ///
+    /// It is drawn with the placeholder color and cannot be edited.
+    pub fn suffix(mut self, suffix: String) -> Self {
+        self.suffix = Some(suffix);
+        self
     }
 
-    fn on_event(
-        &mut self,
-        event: Event,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        renderer: &Renderer,
-        clipboard: &mut dyn Clipboard,
-        shell: &mut Shell<'_, Message>,
-    ) -> event::Status {
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                let is_clicked = layout.bounds().contains(cursor_position);
+    /// Sets the distance in pixels that the pointer needs to travel after a
+    /// press before a selection drag starts in the [`SearchablePickList`].
+    ///
+    /// Smaller movements are treated as a click. It is `3.0` by default.
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.drag_threshold = threshold.max(0.0);
+        self
+    }
 
-                let event_status = if is_clicked {
-                    if !self.state.pick_list.is_open {
-                        let selected = self.selected.as_ref();
+    /// Sets whether the text of the [`SearchablePickList`] can be edited.
+    ///
+    /// When it is not editable, pressing `Delete` or `Backspace` clears the
+    /// current selection instead. It is editable by default.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
 
-                        self.state.pick_list.is_open = true;
-                        self.state.pick_list.hovered_option = self
-                            .options
-                            .iter()
-                            .position(|option| Some(option) == selected);
+    /// Sets the maximum amount of characters that can be typed in the
+    /// [`SearchablePickList`].
+    ///
+    /// Typing past it is ignored and pasted text is truncated to fit.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
 
-                        self.state.is_focused = true;
+    /// Sets whether the [`SearchablePickList`] can be interacted with.
+    ///
+    /// A disabled [`SearchablePickList`] ignores every event, can never be
+    /// focused nor opened, and is drawn with the [`StyleSheet::disabled`]
+    /// style. It is enabled by default.
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
 
-                        event::Status::Captured
-                    } else {
-                        let arrow_down_bounds = Rectangle {
-                            x: layout.bounds().x + layout.bounds().width
-                                - f32::from(self.padding.horizontal())
-                                - 30.0,
-                            y: layout.bounds().y,
-                            ..layout.bounds()
-                        };
+    /// Sets whether the [`SearchablePickList`] only displays its selected
+    /// option.
+    ///
+    /// A read-only [`SearchablePickList`] ignores every event and can never
+    /// be opened, like a disabled one, but it keeps its normal style and
+    /// hides its arrow. It is not read-only by default.
+    pub fn read_only(mut self, is_read_only: bool) -> Self {
+        self.is_read_only = is_read_only;
+        self
+    }
 
-                        if arrow_down_bounds.contains(cursor_position) {
-                            self.state.pick_list.is_open = false;
-                            self.state.is_focused = false;
+    /// Sets whether the text of the [`SearchablePickList`] is masked, like a
+    /// secure password input.
+    ///
+    /// The options of the menu are still displayed as usual.
+    pub fn password(mut self, is_secure: bool) -> Self {
+        self.is_secure = is_secure;
+        self
+    }
 
-                            event::Status::Captured
-                        } else {
-                            // Otherwise the user must have clicked inside the text field
-                            self.state.is_focused = true;
+    /// Sets the helper text displayed below the [`SearchablePickList`].
+    ///
+    /// It is hidden while an [`error_text`](Self::error_text) is set.
+    pub fn helper_text(mut self, text: Option<String>) -> Self {
+        self.helper_text = text;
+        self
+    }
 
-                            if self.select_all_first_click && !is_clicked {
-                                self.state.first_click = true;
-                            }
+    /// Sets the error text displayed below the [`SearchablePickList`].
+    pub fn error_text(mut self, text: Option<String>) -> Self {
+        self.error_text = text;
+        self
+    }
 
-                            let text_layout = layout.children().next().unwrap();
-                            let target =
-                                cursor_position.x - text_layout.bounds().x;
+    /// Sets whether text matching none of the options can be committed by
+    /// pressing `Enter` in the [`SearchablePickList`].
+    ///
+    /// The committed text is passed to [`on_custom`](Self::on_custom).
+    pub fn allow_custom(mut self, allow_custom: bool) -> Self {
+        self.allow_custom = allow_custom;
+        self
+    }
 
-                            let click = mouse::Click::new(
-                                cursor_position,
-                                self.state.last_click,
-                            );
+    /// Sets the function that produces a message when custom text is
+    /// committed in the [`SearchablePickList`].
+    pub fn on_custom(
+        mut self,
+        on_custom: impl Fn(String) -> Message + 'static,
+    ) -> Self {
+        self.on_custom = Some(Box::new(on_custom));
+        self
+    }
 
-                            match click.kind() {
-                                click::Kind::Single => {
-                                    if target > 0.0 {
-                                        let value = self.value.clone();
+    /// Sets the message that should be produced when the selection of the
+    /// [`SearchablePickList`] is cleared.
+    ///
+    /// A clear icon is displayed next to the arrow while an option is
+    /// selected. Clicking it also empties the text. In a non-editable
+    /// [`SearchablePickList`], pressing `Delete` or `Backspace` clears the
+    /// selection as well.
+    pub fn on_clear(mut self, message: Message) -> Self {
+        self.on_clear = Some(message);
+        self
+    }
 
-                                        if self.select_all_first_click
-                                            && self.state.first_click
-                                        {
-                                            self.state
-                                                .cursor
-                                                .select_all(&value);
-                                            self.state.first_click = false;
-                                        } else {
-                                            let position = find_cursor_position(
-                                                    renderer,
-                                                    text_layout.bounds(),
-                                                    self.font.clone(),
-                                                    self.size,
-                                                    &value,
-                                                    self.state.is_focused,
-                                                    self.state.cursor,
-                                                    target,
-                                                );
+    /// Sets the message that should be produced when the menu of the
+    /// [`SearchablePickList`] opens.
+    pub fn on_open(mut self, message: Message) -> Self {
+        self.on_open = Some(message);
+        self
+    }
 
-                                            self.state.cursor.move_to(position);
+    /// Sets the message that should be produced when the menu of the
+    /// [`SearchablePickList`] closes.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
 
-                                            self.state.is_dragging = true;
-                                        }
-                                    } else {
-                                        self.state.cursor.move_to(0);
+    /// Sets the function that produces a message whenever a different option
+    /// is hovered in the menu of the [`SearchablePickList`].
+    ///
+    /// The option under the mouse is reported while there is one, and the
+    /// option highlighted with the keyboard otherwise.
+    pub fn on_hover_option(
+        mut self,
+        on_hover_option: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        self.on_hover_option = Some(Box::new(on_hover_option));
+        self
+    }
 
-                                        self.state.is_dragging = true;
-                                    }
-                                }
-                                click::Kind::Double => {
-                                    let position = find_cursor_position(
-                                            renderer,
-                                            text_layout.bounds(),
-                                            self.font.clone(),
-                                            self.size,
-                                            &self.value,
-                                            self.state.is_focused,
-                                            self.state.cursor,
-                                            target,
-                                        );
+    /// Sets the time an option needs to stay hovered before the
+    /// [`on_hover_option`](Self::on_hover_option) message is produced.
+    ///
+    /// The hover is checked whenever the [`SearchablePickList`] receives an
+    /// event, like the pointer moving. Once the pointer rests on an option,
+    /// the message is only produced by [`State::poll`].
+    pub fn hover_debounce(mut self, debounce: Duration) -> Self {
+        self.hover_debounce = debounce;
+        self
+    }
 
-                                    self.state.cursor.select_range(
-                                        self.value
-                                            .previous_start_of_word(position),
-                                        self.value.next_end_of_word(position),
-                                    );
+    /// Sets the function that produces a message whenever typing changes the
+    /// amount of options displayed in the menu of the [`SearchablePickList`].
+    ///
+    /// The message is debounced along with `on_change`, so it may also be
+    /// produced as a [`Deferred::Filtered`] update by [`State::poll`].
+    pub fn on_filtered(
+        mut self,
+        on_filtered: impl Fn(usize) -> Message + 'static,
+    ) -> Self {
+        self.on_filtered = Some(Box::new(on_filtered));
+        self
+    }
 
-                                    self.state.is_dragging = false;
-                                }
-                                click::Kind::Triple => {
-                                    self.state.cursor.select_all(&self.value);
-                                    self.state.is_dragging = false;
-                                }
-                            }
+    /// Sets whether several options of the [`SearchablePickList`] can be
+    /// selected at once.
+    ///
+    /// The options of the menu display checkboxes, and picking one toggles
+    /// it without closing the menu. The toggled options are kept in the
+    /// [`State`] and summarized, separated by commas, while the
+    /// [`SearchablePickList`] is closed.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
 
-                            self.state.last_click = Some(click);
+    /// Sets the function that produces a message whenever an option is
+    /// toggled while [`multi_select`](Self::multi_select) is enabled.
+    ///
+    /// The function takes the option and whether it is now selected.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(T, bool) -> Message + 'static,
+    ) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
 
-                            event::Status::Captured
-                        }
-                    }
-                } else {
-                    self.state.pick_list.is_open = false;
-                    self.state.is_focused = false;
+    /// Sets whether the options toggled while
+    /// [`multi_select`](Self::multi_select) is enabled should be reported
+    /// all at once, when the menu closes.
+    ///
+    /// While deferred, toggling an option produces no
+    /// [`on_toggle`](Self::on_toggle) message. Instead, the toggles
+    /// accumulate in the [`State`] and a single
+    /// [`on_selections`](Self::on_selections) message is produced when the
+    /// menu closes, if anything was toggled. It is disabled by default.
+    pub fn defer_selection_until_close(mut self, defer: bool) -> Self {
+        self.defer_selection_until_close = defer;
+        self
+    }
 
-                    event::Status::Ignored
-                };
+    /// Sets the function that produces a message with all the selected
+    /// options when the menu closes, if
+    /// [`defer_selection_until_close`](Self::defer_selection_until_close) is
+    /// enabled and some were toggled.
+    pub fn on_selections(
+        mut self,
+        on_selections: impl Fn(Vec<T>) -> Message + 'static,
+    ) -> Self {
+        self.on_selections = Some(Box::new(on_selections));
+        self
+    }
+
+    /// Sets the function that produces the text color of every option of the
+    /// [`SearchablePickList`].
+    ///
+    /// The color is used both in the menu and for the selected option while
+    /// the menu is closed. The [`StyleSheet`] colors are used when it returns
+    /// `None`.
+    pub fn option_color(
+        mut self,
+        option_color: impl Fn(&T) -> Option<Color> + 'static,
+    ) -> Self {
+        self.option_color = Some(Box::new(option_color));
+        self
+    }
+
+    /// Sets the function that decides whether every option of the
+    /// [`SearchablePickList`] can be selected.
+    ///
+    /// Disabled options are still displayed in the menu, dimmed, but they
+    /// are skipped by the keyboard navigation and clicking them does
+    /// nothing. All the options are enabled by default.
+    pub fn option_enabled(
+        mut self,
+        option_enabled: impl Fn(&T) -> bool + 'static,
+    ) -> Self {
+        self.option_enabled = Some(Box::new(option_enabled));
+        self
+    }
+
+    /// Sets the function that produces the group of every option of the
+    /// [`SearchablePickList`].
+    ///
+    /// The menu displays a header with the name of the group before every
+    /// run of consecutive options in the same group, so the options should
+    /// already be sorted by group.
+    pub fn group_by(
+        mut self,
+        group_by: impl Fn(&T) -> Cow<'_, str> + 'static,
+    ) -> Self {
+        self.group_by = Some(Box::new(group_by));
+        self
+    }
+
+    /// Sets the horizontal alignment of the label of the
+    /// [`SearchablePickList`] while its menu is closed.
+    ///
+    /// The alignment of the text being edited is set with
+    /// [`text_alignment`](Self::text_alignment).
+    pub fn collapsed_alignment(
+        mut self,
+        alignment: alignment::Horizontal,
+    ) -> Self {
+        self.collapsed_alignment = alignment;
+        self
+    }
+
+    /// Sets the horizontal alignment of the text being edited in the
+    /// [`SearchablePickList`], like right-aligning numeric values.
+    ///
+    /// The alignment only applies while the text fits in the field. Longer
+    /// text scrolls with the cursor as usual. It is left-aligned by default.
+    pub fn text_alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.text_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`LayoutDirection`] of the [`SearchablePickList`].
+    ///
+    /// Laid out from right to left, the arrow and the suffix are placed at
+    /// the left, the prefix at the right, and the rows of the menu are
+    /// mirrored. The text and the closed label are also right-aligned, unless
+    /// their alignment is set afterwards.
+    pub fn layout_direction(mut self, direction: LayoutDirection) -> Self {
+        self.layout_direction = direction;
+
+        if direction == LayoutDirection::RightToLeft {
+            self.text_alignment = alignment::Horizontal::Right;
+            self.collapsed_alignment = alignment::Horizontal::Right;
+        }
+
+        self
+    }
+
+    /// Returns the current [`State`] of the [`SearchablePickList`].
+    pub fn state(&self) -> &State<T> {
+        self.state
+    }
+
+    /// Sets the message to show if the options list of the [`SearchablePickList`] is empty.
+    pub fn options_empty_message(mut self, message: String) -> Self {
+        self.options_empty_message = Some(message);
+        self
+    }
+
+    /// Sets the height of the row displaying the message of an empty options
+    /// list of the [`SearchablePickList`].
+    pub fn options_empty_message_height(mut self, height: u16) -> Self {
+        self.options_empty_message_height = Some(height);
+        self
+    }
+
+    /// Sets whether the message of an empty options list of the
+    /// [`SearchablePickList`] should be centered in its row.
+    pub fn center_options_empty_message(mut self, center: bool) -> Self {
+        self.center_options_empty_message = center;
+        self
+    }
+
+    /// Sets whether the selected option should be hovered when the menu of
+    /// the [`SearchablePickList`] opens.
+    ///
+    /// This is enabled by default.
+    pub fn prehover_selected(mut self, prehover: bool) -> Self {
+        self.prehover_selected = prehover;
+        self
+    }
+
+    /// Sets whether the menu of the [`SearchablePickList`] should open when
+    /// the field is hovered, previewing the options without focusing it.
+    ///
+    /// While previewing, keyboard input is ignored and options can only be
+    /// selected with the mouse.
+    pub fn open_on_hover(mut self, open_on_hover: bool) -> Self {
+        self.open_on_hover = open_on_hover;
+        self
+    }
+
+    /// Sets the [`Font`] of the options in the menu of the
+    /// [`SearchablePickList`].
+    ///
+    /// The [`font`](Self::font) of the text field is used by default.
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn menu_font(mut self, font: Renderer::Font) -> Self {
+        self.menu_font = Some(font);
+        self
+    }
+
+    /// Sets the text size of the options in the menu of the
+    /// [`SearchablePickList`].
+    ///
+    /// The [`size`](Self::size) of the text field is used by default.
+    pub fn menu_text_size(mut self, size: u16) -> Self {
+        self.menu_text_size = Some(size);
+        self
+    }
+
+    /// Sets the maximum height of the menu of the [`SearchablePickList`].
+    ///
+    /// The options become scrollable past this height. The menu height is
+    /// only limited by the available space by default.
+    pub fn menu_max_height(mut self, max_height: u16) -> Self {
+        self.menu_max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the maximum amount of options displayed at once in the menu of
+    /// the [`SearchablePickList`].
+    ///
+    /// The height of the rows follows the text size of the menu and the
+    /// padding of the [`SearchablePickList`]. If a
+    /// [`menu_max_height`](Self::menu_max_height) is also set, the smaller
+    /// height wins.
+    pub fn menu_max_rows(mut self, max_rows: usize) -> Self {
+        self.menu_max_rows = Some(max_rows);
+        self
+    }
+
+    /// Sets the minimum width of the menu of the [`SearchablePickList`].
+    ///
+    /// The menu is widened past the field to fit long options, but it is
+    /// never narrower than the field. Either way, it stays aligned to the
+    /// left edge of the field.
+    pub fn menu_min_width(mut self, min_width: u16) -> Self {
+        self.menu_min_width = min_width;
+        self
+    }
+
+    /// Sets the time the pointer needs to hover an unfocused
+    /// [`SearchablePickList`] before its menu opens when
+    /// [`open_on_hover`](Self::open_on_hover) is enabled.
+    ///
+    /// The delay is checked whenever the pointer moves, and it starts over
+    /// if the pointer leaves the [`SearchablePickList`] before it elapses.
+    /// If the pointer rests on the [`SearchablePickList`] instead, the menu
+    /// is opened by [`State::poll`].
+    pub fn hover_open_delay(mut self, delay: Duration) -> Self {
+        self.hover_open_delay = delay;
+        self
+    }
+
+    /// Sets the regions where clicking outside of the [`SearchablePickList`]
+    /// will not close its menu nor unfocus it.
+    ///
+    /// The regions are expressed in the same absolute coordinates as the
+    /// layout bounds of the widgets, with the origin at the top-left corner
+    /// of the window.
+    pub fn ignore_outside_clicks_in(mut self, regions: Vec<Rectangle>) -> Self {
+        self.ignored_outside_clicks = regions;
+        self
+    }
+
+    /// Sets whether the [`SearchablePickList`] should select an option as
+    /// soon as it is the only one matching the text being typed.
+    ///
+    /// Selecting the option closes the menu and unfocuses the
+    /// [`SearchablePickList`], so it is only selected once.
+    pub fn auto_select_single_match(mut self, auto_select: bool) -> Self {
+        self.auto_select_single_match = auto_select;
+        self
+    }
+
+    /// Sets whether typing while the [`SearchablePickList`] is focused but
+    /// closed should select an option without opening the menu.
+    ///
+    /// Like in a native `<select>`, the characters typed in quick succession
+    /// select the first option starting with them. The text of the
+    /// [`SearchablePickList`] is left untouched.
+    pub fn type_ahead(mut self, type_ahead: bool) -> Self {
+        self.type_ahead = type_ahead;
+        self
+    }
+
+    /// Sets whether the options matching the text of the
+    /// [`SearchablePickList`] should be deduplicated by label.
+    ///
+    /// While filtering, only the first of the options with the same label is
+    /// shown in the menu. It is disabled by default, since distinct options
+    /// may share a label.
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Sets whether scrolling the mouse wheel over the closed
+    /// [`SearchablePickList`] should select the previous or next option.
+    ///
+    /// The selection stops at the first and last options. It is disabled by
+    /// default.
+    pub fn wheel_cycle(mut self, wheel_cycle: bool) -> Self {
+        self.wheel_cycle = wheel_cycle;
+        self
+    }
+
+    /// Sets whether hovering the closed [`SearchablePickList`] should show
+    /// the full label of the selected option in a tooltip, when it is too
+    /// long to fit in the control.
+    ///
+    /// It is disabled by default.
+    pub fn show_full_on_hover(mut self, show_full_on_hover: bool) -> Self {
+        self.show_full_on_hover = show_full_on_hover;
+        self
+    }
+
+    /// Sets whether selecting an option from the menu should replace the
+    /// text of the [`SearchablePickList`] with the label of the option.
+    ///
+    /// The new text is reported through `on_change` right before
+    /// `on_selected`, bypassing any debounce. It is enabled by default.
+    pub fn sync_text_on_select(mut self, sync: bool) -> Self {
+        self.sync_text_on_select = sync;
+        self
+    }
+
+    /// Sets whether picking the option that is already selected should not
+    /// produce an `on_selected` message.
+    ///
+    /// The menu is closed either way. It is disabled by default, so every
+    /// explicit pick is reported.
+    pub fn skip_if_unchanged(mut self, skip: bool) -> Self {
+        self.skip_if_unchanged = skip;
+        self
+    }
+
+    /// Sets whether pressing Escape should abandon the edit, reverting the
+    /// text of the [`SearchablePickList`] to the label of the selected
+    /// option.
+    ///
+    /// The text is emptied if no option is selected, and the change is
+    /// reported through `on_change`. It is disabled by default.
+    pub fn restore_on_escape(mut self, restore: bool) -> Self {
+        self.restore_on_escape = restore;
+        self
+    }
+
+    /// Sets the amount of characters that need to be typed before the menu
+    /// of the [`SearchablePickList`] is displayed.
+    ///
+    /// Below it, the options are not filtered, but `on_change` messages are
+    /// still produced. It is `0` by default.
+    pub fn min_search_length(mut self, length: usize) -> Self {
+        self.min_search_length = length;
+        self
+    }
+
+    /// Sets how the options matching the text of the [`SearchablePickList`]
+    /// are highlighted in its menu.
+    ///
+    /// It is [`MatchHighlightMode::Span`] by default.
+    pub fn match_highlight_mode(mut self, mode: MatchHighlightMode) -> Self {
+        self.match_highlight_mode = mode;
+        self
+    }
+
+    /// Sets whether the options of the [`SearchablePickList`] are loading.
+    ///
+    /// While loading, the dropdown arrow is replaced by a spinner. The
+    /// spinner starts as soon as the [`SearchablePickList`] is built loading
+    /// and advances every time it is redrawn, so the application should keep
+    /// redrawing it while it loads, like by ticking its [`State`] as
+    /// described in [`State::needs_tick`].
+    ///
+    /// An empty options list displays the
+    /// [`loading_message`](Self::loading_message) instead of the
+    /// [`options_empty_message`](Self::options_empty_message).
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self.state.loading_since = if loading {
+            Some(self.loading_since.unwrap_or_else(Instant::now))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Sets the message to show if the options list of the
+    /// [`SearchablePickList`] is empty while they are loading.
+    ///
+    /// It is `Loading…` by default.
+    pub fn loading_message(mut self, message: String) -> Self {
+        self.loading_message = Some(message);
+        self
+    }
+
+    /// Sets the icon displayed at the right side of the
+    /// [`SearchablePickList`], drawn with the given [`Font`].
+    ///
+    /// The dropdown arrow of the [`Renderer`] is displayed by default.
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    /// [`Renderer`]: crate::widget::text::Renderer
+    pub fn icon(mut self, icon: char, font: Renderer::Font) -> Self {
+        self.icon = Some((icon, font));
+        self
+    }
+
+    /// Sets the function used to score every option against the current
+    /// search text, displaying the result on the right side of each row of
+    /// the menu of the [`SearchablePickList`].
+    ///
+    /// This is meant as a debugging aid while tuning search relevance and is
+    /// not intended to be used in production.
+    pub fn show_match_scores(
+        mut self,
+        match_score: impl Fn(&T, &str) -> f32 + 'static,
+    ) -> Self {
+        self.match_score = Some(Box::new(match_score));
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> SearchablePickList<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+{
+    /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
+    /// [`Value`] if provided.
+    pub fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        value: Option<&Value>,
+    ) {
+        let value = value.unwrap_or(&self.value);
+        let bounds = field_bounds(layout, self.padding);
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let show_clear_icon = self.shows_clear_icon();
+
+        let label = self.closed_label();
+
+        draw(
+            renderer,
+            bounds,
+            text_bounds,
+            cursor_position,
+            Field {
+                pick_list_is_open: self.is_open(),
+                is_enabled: self.is_enabled,
+                selected: label.as_ref(),
+                selected_color: self
+                    .selected
+                    .as_ref()
+                    .filter(|_| !self.multi_select)
+                    .and_then(|selected| {
+                        self.option_color
+                            .as_ref()
+                            .and_then(|option_color| option_color(selected))
+                    }),
+                font: &self.font,
+                text_size: self.size,
+                placeholder: self.placeholder(),
+                is_placeholder_disabled: self
+                    .active_disabled_placeholder()
+                    .is_some(),
+                prefix: self.prefix.as_deref(),
+                suffix: self.suffix.as_deref(),
+                padding: self.padding,
+                arrow_width: self.arrow_width(),
+                right_to_left: self.is_right_to_left(),
+                collapsed_alignment: self.collapsed_alignment,
+                text_alignment: self.text_alignment,
+                wrap: self.wrap,
+                value,
+                is_secure: self.is_secure,
+                is_focused: self.state.is_focused,
+                cursor: self.state.cursor,
+                is_caret_visible: !self.caret_blink
+                    || is_caret_visible(self.state.caret_since, Instant::now()),
+                scroll_offset: self.state.scroll_offset,
+                selection_opacity: self.selection_opacity,
+                underline: self.underline,
+                spinner: self.loading.then(|| {
+                    spinner_frame(self.state.loading_since, Instant::now())
+                }),
+                icon: self.icon.as_ref().map(|(icon, font)| (*icon, font)),
+                show_arrow: !self.is_read_only,
+                show_clear_icon,
+                style_sheet: self.style_sheet.as_ref(),
+            },
+        );
+
+        if let Some((message, color)) = validation_message(
+            self.helper_text.as_deref(),
+            self.error_text.as_deref(),
+            self.style_sheet.as_ref(),
+        ) {
+            let message_bounds = layout.children().nth(1).unwrap().bounds();
+
+            renderer.fill_text(Text {
+                content: message,
+                bounds: message_bounds,
+                size: message_bounds.height,
+                font: self.font.clone(),
+                color,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+            });
+        }
+    }
+
+    /// Returns the label displayed while the [`SearchablePickList`] is
+    /// closed, if an option is selected.
+    fn closed_label(&self) -> Option<String> {
+        if self.multi_select {
+            let summary = self
+                .state
+                .selected_options
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            (!summary.is_empty()).then(|| summary)
+        } else {
+            self.selected.as_ref().map(ToString::to_string)
+        }
+    }
+
+    /// Returns how many characters can still be typed or pasted in the
+    /// [`SearchablePickList`], given that they replace the selected text.
+    fn remaining_length(&self) -> usize {
+        let max_length = match self.max_length {
+            Some(max_length) => max_length,
+            None => return usize::MAX,
+        };
+
+        let selected = self
+            .state
+            .cursor
+            .selection(&self.value)
+            .map_or(0, |(start, end)| end - start);
+
+        max_length.saturating_sub(self.value.len() - selected)
+    }
+
+    /// Returns the placeholder of the [`SearchablePickList`], which depends
+    /// on whether it is focused or disabled.
+    fn placeholder(&self) -> &str {
+        if let Some(placeholder) = self.active_disabled_placeholder() {
+            return placeholder;
+        }
+
+        match &self.placeholder_focused {
+            Some(placeholder) if self.state.is_focused => placeholder,
+            _ => &self.placeholder,
+        }
+    }
+
+    /// Returns the placeholder of the [`SearchablePickList`] while it is
+    /// disabled, if any.
+    fn active_disabled_placeholder(&self) -> Option<&str> {
+        self.disabled_placeholder
+            .as_deref()
+            .filter(|_| !self.is_enabled)
+    }
+
+    fn is_right_to_left(&self) -> bool {
+        self.layout_direction == LayoutDirection::RightToLeft
+    }
+
+    /// Returns whether the menu of the [`SearchablePickList`] is open and
+    /// enough text has been typed to display it.
+    fn is_menu_visible(&self) -> bool {
+        self.is_open() && self.value.len() >= self.min_search_length
+    }
+
+    /// Returns whether the [`SearchablePickList`] is open and can be
+    /// interacted with.
+    fn is_open(&self) -> bool {
+        self.state.pick_list.is_open && self.is_enabled && !self.is_read_only
+    }
+
+    /// Returns the width reserved for the arrow of the [`SearchablePickList`],
+    /// which is hidden when it is read-only.
+    fn arrow_width(&self) -> f32 {
+        if self.is_read_only {
+            0.0
+        } else {
+            self.arrow_reserved_width
+        }
+    }
+
+    /// Returns whether the clear icon of the [`SearchablePickList`] is
+    /// displayed.
+    fn shows_clear_icon(&self) -> bool {
+        self.is_enabled
+            && !self.is_read_only
+            && self.on_clear.is_some()
+            && self.selected.is_some()
+    }
+}
+
+impl<'a, T, Message, Renderer> SearchablePickList<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    /// Keeps the text cursor of the [`SearchablePickList`] visible by updating
+    /// the stored horizontal scroll offset.
+    fn scroll_to_cursor(
+        &mut self,
+        measurements: &mut Measurements<'_, Renderer>,
+        layout: Layout<'_>,
+    ) {
+        if !self.state.is_focused {
+            self.state.scroll_offset = 0.0;
+
+            return;
+        }
+
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let value = self.displayed_value();
+
+        measurements.set_value(&value);
+
+        let focus_position = match self.state.cursor.state(&value) {
+            cursor::State::Index(i) => i,
+            cursor::State::Selection { end, .. } => end,
+        };
+
+        let cursor_x = measurements.width(focus_position);
+        let text_width = measurements.width(value.len());
+
+        self.state.scroll_offset = next_scroll_offset(
+            self.state.scroll_offset,
+            cursor_x,
+            text_width,
+            self.editable_width(text_bounds, measurements.renderer),
+        );
+    }
+
+    /// Returns how far the text of the [`SearchablePickList`] is scrolled,
+    /// which is negative when it is shifted right by its alignment.
+    fn text_offset(
+        &self,
+        measurements: &mut Measurements<'_, Renderer>,
+        layout: Layout<'_>,
+    ) -> f32 {
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let text_width = measurements.text_width();
+
+        self.state.scroll_offset
+            - alignment_offset(
+                self.text_alignment,
+                text_width,
+                self.editable_width(text_bounds, measurements.renderer),
+            )
+    }
+
+    /// Returns the width available to the text being edited in the given
+    /// text bounds of the [`SearchablePickList`].
+    ///
+    /// From right to left, the layout already leaves out the trailing
+    /// content.
+    fn editable_width(
+        &self,
+        text_bounds: Rectangle,
+        renderer: &Renderer,
+    ) -> f32 {
+        if self.is_right_to_left() {
+            text_bounds.width
+        } else {
+            text_bounds.width - self.trailing_width(renderer)
+        }
+    }
+
+    /// Returns the label of the selected option, if it should be shown in
+    /// full on top of the closed [`SearchablePickList`].
+    ///
+    /// The label is only shown while hovered, when it does not fit in the
+    /// control and would be truncated.
+    fn full_label(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<String> {
+        if !self.show_full_on_hover
+            || !self.state.is_hovered
+            || self.is_open()
+            || self.wrap
+        {
+            return None;
+        }
+
+        let label = self.selected.as_ref()?.to_string();
+
+        let max_width = field_bounds(layout, self.padding).width
+            - f32::from(self.padding.horizontal())
+            - self.affix_width(renderer, self.prefix.as_deref())
+            - self.trailing_width(renderer);
+
+        let width = renderer.measure_width(
+            &label,
+            self.size.unwrap_or(renderer.default_size()),
+            self.font.clone(),
+        );
+
+        (width > max_width).then(|| label)
+    }
+
+    /// Returns the width taken by the suffix, the clear icon and the arrow
+    /// at the end of the text of the [`SearchablePickList`].
+    fn trailing_width(&self, renderer: &Renderer) -> f32 {
+        let clear_icon_width = if self.shows_clear_icon() {
+            CLEAR_ICON_WIDTH
+        } else {
+            0.0
+        };
+
+        self.affix_width(renderer, self.suffix.as_deref())
+            + clear_icon_width
+            + self.arrow_width()
+    }
+
+    /// Returns the width of the given prefix or suffix of the
+    /// [`SearchablePickList`].
+    fn affix_width(&self, renderer: &Renderer, affix: Option<&str>) -> f32 {
+        affix.map_or(0.0, |affix| {
+            renderer.measure_width(
+                affix,
+                self.size.unwrap_or(renderer.default_size()),
+                self.font.clone(),
+            )
+        })
+    }
+
+    /// Clears the selection and the text of the [`SearchablePickList`].
+    fn clear(&mut self, shell: &mut Shell<'_, Message>) {
+        self.selected = None;
+
+        self.state.history.record(&self.value, self.state.cursor);
+        self.value = Value::new("");
+        self.state.cursor.move_to(0);
+        self.state.pending_change = None;
+
+        shell.publish((self.on_change)(String::new()));
+
+        if let Some(on_clear) = self.on_clear.clone() {
+            shell.publish(on_clear);
+        }
+
+        self.value_changed(shell);
+    }
+
+    /// Selects the whole text before copying or cutting it if nothing is
+    /// selected and [`whole_line_on_no_selection`] is enabled.
+    ///
+    /// Secure text cannot be copied, so it is never selected.
+    ///
+    /// [`whole_line_on_no_selection`]: Self::whole_line_on_no_selection
+    fn select_whole_line_if_unselected(&mut self) {
+        if self.whole_line_on_no_selection
+            && !self.is_secure
+            && self.state.cursor.selection(&self.value).is_none()
+        {
+            self.state.cursor.select_all(&self.value);
+        }
+    }
+
+    /// Returns the [`Value`] as it is displayed, masked if the
+    /// [`SearchablePickList`] is secure.
+    fn displayed_value(&self) -> Value {
+        if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        }
+    }
+
+    fn open_menu(&mut self, shell: &mut Shell<'_, Message>) {
+        if self.state.pick_list.is_open {
+            return;
+        }
+
+        self.state.pick_list.is_open = true;
+        self.prepare_menu();
+
+        if let Some(on_open) = self.on_open.clone() {
+            shell.publish(on_open);
+        }
+    }
+
+    /// Filters the options of a menu that was just opened and hovers the
+    /// selected one, if enabled.
+    fn prepare_menu(&mut self) {
+        self.state.is_opening = false;
+
+        self.filter_options();
+
+        let selected = self.selected.as_ref();
+
+        self.state.pick_list.hovered_option = if self.prehover_selected {
+            self.visible_options().iter().position(|option| {
+                Some(option) == selected && self.is_option_enabled(option)
+            })
+        } else {
+            None
+        };
+        self.state.pick_list.menu.pointed_option = None;
+
+        self.scroll_to_option(self.state.pick_list.hovered_option.unwrap_or(0));
+    }
+
+    /// Scrolls the menu of the [`SearchablePickList`] so the visible option
+    /// at the given index is displayed.
+    fn scroll_to_option(&mut self, index: usize) {
+        let options = if self.state.filtered_indices.is_some() {
+            &self.state.filtered_options[..]
+        } else {
+            &self.options[..]
+        };
+
+        self.state.pick_list.menu.scroll_to_option(
+            options,
+            index,
+            self.group_by.as_deref(),
+        );
+    }
+
+    fn close_menu(&mut self, shell: &mut Shell<'_, Message>) {
+        if !self.state.pick_list.is_open {
+            return;
+        }
+
+        self.state.pick_list.is_open = false;
+
+        if std::mem::take(&mut self.state.has_deferred_toggles) {
+            if let Some(on_selections) = &self.on_selections {
+                shell.publish(on_selections(
+                    self.state.selected_options.clone(),
+                ));
+            }
+        }
+
+        if let Some(on_close) = self.on_close.clone() {
+            shell.publish(on_close);
+        }
+    }
+
+    /// Returns the options displayed in the menu of the
+    /// [`SearchablePickList`].
+    fn visible_options(&self) -> &[T] {
+        if self.state.filtered_indices.is_some() {
+            &self.state.filtered_options
+        } else {
+            &self.options
+        }
+    }
+
+    fn is_option_enabled(&self, option: &T) -> bool {
+        self.option_enabled
+            .as_ref()
+            .map_or(true, |option_enabled| option_enabled(option))
+    }
+
+    /// Narrows the options displayed in the menu down to the ones matching
+    /// the text of the [`SearchablePickList`] while it is focused.
+    fn filter_options(&mut self) {
+        let query = self.value.to_string();
+
+        if !self.state.is_focused
+            || query.is_empty()
+            || self.value.len() < self.min_search_length
+        {
+            self.state.filtered_indices = None;
+            self.state.filtered_options.clear();
+
+            return;
+        }
+
+        let mut indices = filter(&self.options, &query);
+
+        if self.dedup {
+            indices = dedup(&self.options, indices);
+        }
+
+        self.state.filtered_options =
+            indices.iter().map(|&i| self.options[i].clone()).collect();
+
+        if let Some(hovered) = self.state.pick_list.hovered_option {
+            if hovered >= indices.len() {
+                self.state.pick_list.hovered_option = None;
+            }
+        }
+
+        if let Some(pointed) = self.state.pick_list.menu.pointed_option {
+            if pointed >= indices.len() {
+                self.state.pick_list.menu.pointed_option = None;
+            }
+        }
+
+        self.state.filtered_indices = Some(indices);
+    }
+
+    /// Moves the hovered option of the menu down or up to the next enabled
+    /// one, wrapping around at the ends, and scrolls it into view.
+    fn hover_next_option(&mut self, down: bool) {
+        let count = self.visible_options().len();
+        let mut hovered = self.state.pick_list.hovered_option;
+
+        for _ in 0..count {
+            let next = match (hovered, down) {
+                (Some(index), true) => (index + 1) % count,
+                (Some(index), false) => (index + count - 1) % count,
+                (None, true) => 0,
+                (None, false) => count - 1,
+            };
+
+            if self.is_option_enabled(&self.visible_options()[next]) {
+                self.state.pick_list.hovered_option = Some(next);
+                self.scroll_to_option(next);
+
+                return;
+            }
+
+            hovered = Some(next);
+        }
+    }
+
+    /// Returns the maximum height of the menu, given its text size, out of
+    /// the [`menu_max_height`](Self::menu_max_height) and the
+    /// [`menu_max_rows`](Self::menu_max_rows).
+    fn effective_menu_max_height(&self, text_size: u16) -> Option<u16> {
+        let row_height = text_size + self.padding.vertical();
+
+        // The options are surrounded by a border of 1 unit
+        let rows_height = self.menu_max_rows.map(|max_rows| {
+            u16::try_from(max_rows)
+                .unwrap_or(u16::MAX)
+                .saturating_mul(row_height)
+                .saturating_add(2)
+        });
+
+        match (self.menu_max_height, rows_height) {
+            (Some(max_height), Some(rows_height)) => {
+                Some(max_height.min(rows_height))
+            }
+            (max_height, rows_height) => max_height.or(rows_height),
+        }
+    }
+
+    /// Moves the hovered option of the menu down or up by the amount of
+    /// options that fit in the maximum height of the menu, stopping at the
+    /// ends.
+    ///
+    /// Without a maximum height, the first or last option is hovered.
+    fn hover_next_page(&mut self, down: bool, text_size: u16) {
+        let row_height = f32::from(text_size + self.padding.vertical());
+        let max_height = self.effective_menu_max_height(text_size);
+
+        let page = max_height.map_or(usize::MAX, |max_height| {
+            ((f32::from(max_height) / row_height) as usize).max(1)
+        });
+
+        let index = match (self.state.pick_list.hovered_option, down) {
+            (Some(index), true) => index.saturating_add(page),
+            (Some(index), false) => index.saturating_sub(page),
+            (None, true) => 0,
+            (None, false) => usize::MAX,
+        };
+
+        self.hover_enabled_option(index, down);
+    }
+
+    /// Hovers the enabled option of the menu closest to the given index,
+    /// looking down or up first, and scrolls it into view.
+    fn hover_enabled_option(&mut self, index: usize, down: bool) {
+        let count = self.visible_options().len();
+
+        if count == 0 {
+            return;
+        }
+
+        let index = index.min(count - 1);
+        let is_enabled =
+            |&i: &usize| self.is_option_enabled(&self.visible_options()[i]);
+
+        let below = || (index..count).find(is_enabled);
+        let above = || (0..=index).rev().find(is_enabled);
+
+        let hovered = if down {
+            below().or_else(above)
+        } else {
+            above().or_else(below)
+        };
+
+        if let Some(hovered) = hovered {
+            self.state.pick_list.hovered_option = Some(hovered);
+            self.scroll_to_option(hovered);
+        }
+    }
+
+    fn value_changed(&mut self, shell: &mut Shell<'_, Message>) {
+        self.filter_options();
+        self.notify_filtered(shell);
+        self.select_single_match(shell);
+    }
+
+    /// Produces the `on_filtered` message if the amount of visible options
+    /// changed since it was last reported, unless an edit is debounced.
+    fn notify_filtered(&mut self, shell: &mut Shell<'_, Message>) {
+        if self.on_filtered.is_none() {
+            return;
+        }
+
+        self.state.filtered_count = Some(self.visible_options().len());
+
+        if self.state.pending_change.is_some() {
+            return;
+        }
+
+        if let Some(count) = self.state.take_unreported_filtered() {
+            if let Some(on_filtered) = &self.on_filtered {
+                shell.publish(on_filtered(count));
+            }
+        }
+    }
+
+    /// Produces the `on_change` message for the given text, unless it is
+    /// debounced.
+    fn publish_change(
+        &mut self,
+        contents: String,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if self.debounce == Duration::ZERO {
+            shell.publish((self.on_change)(contents));
+        } else {
+            self.state.pending_change =
+                Some((Value::new(&contents), Instant::now() + self.debounce));
+        }
+    }
+
+    /// Produces the `on_change` message of a debounced edit once the
+    /// debounce duration has elapsed at the given [`Instant`].
+    fn flush_change(&mut self, now: Instant, shell: &mut Shell<'_, Message>) {
+        if let Some(value) = self.state.take_due_change(now) {
+            shell.publish((self.on_change)(value.to_string()));
+            self.notify_filtered(shell);
+        }
+    }
+
+    fn notify_hovered_option(&mut self, shell: &mut Shell<'_, Message>) {
+        let on_hover_option = match &self.on_hover_option {
+            Some(on_hover_option) => on_hover_option,
+            None => return,
+        };
+
+        if !self.is_menu_visible() {
+            self.state.pending_hover = None;
+            self.state.reported_hover = None;
+
+            return;
+        }
+
+        // The option under the mouse takes precedence over the keyboard
+        // highlight while there is one
+        let hovered = self
+            .state
+            .pick_list
+            .menu
+            .pointed_option
+            .or(self.state.pick_list.hovered_option);
+        let pending =
+            self.state.pending_hover.as_ref().map(|(index, ..)| *index);
+
+        // An option is only reported again once another one was hovered
+        if hovered != pending.or(self.state.reported_hover) {
+            self.state.reported_hover = None;
+            self.state.pending_hover = hovered.and_then(|index| {
+                let option = self.visible_options().get(index)?.clone();
+
+                Some((index, option, Instant::now() + self.hover_debounce))
+            });
+        }
+
+        if let Some(option) = self.state.take_due_hover(Instant::now()) {
+            shell.publish(on_hover_option(option));
+        }
+    }
+
+    fn select_single_match(&mut self, shell: &mut Shell<'_, Message>) {
+        if !self.auto_select_single_match {
+            return;
+        }
+
+        if self.state.filtered_indices.is_none() {
+            return;
+        }
+
+        if let [option] = &self.state.filtered_options[..] {
+            if self.selected.as_ref() != Some(option)
+                && self.is_option_enabled(option)
+            {
+                let option = option.clone();
+
+                self.select(option, shell);
+            }
+        }
+    }
+
+    /// Returns the option hovered in the open menu, if any.
+    fn hovered_option(&self) -> Option<T> {
+        if !self.is_menu_visible() {
+            return None;
+        }
+
+        self.state
+            .pick_list
+            .hovered_option
+            .and_then(|index| self.visible_options().get(index))
+            .filter(|option| self.is_option_enabled(option))
+            .cloned()
+    }
+
+    /// Selects the enabled option after the selected one, or before it if
+    /// not `forward`, while keeping the menu closed.
+    fn select_adjacent(
+        &mut self,
+        forward: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let count = self.options.len();
+        let index = self.selected.as_ref().and_then(|selected| {
+            self.options.iter().position(|option| option == selected)
+        });
+
+        let is_enabled =
+            |&index: &usize| self.is_option_enabled(&self.options[index]);
+
+        let index = if forward {
+            (index.map_or(0, |index| index + 1)..count).find(is_enabled)
+        } else {
+            (0..index.unwrap_or(count)).rev().find(is_enabled)
+        };
+
+        let option = index.map(|index| self.options[index].clone());
+
+        if let Some(option) = option {
+            self.selected = Some(option.clone());
+
+            shell.publish((self.on_selected)(option));
+        }
+    }
+
+    /// Adds the given character to the ones typed ahead, selecting the first
+    /// option starting with them while keeping the menu closed.
+    fn select_typed_ahead(&mut self, c: char, shell: &mut Shell<'_, Message>) {
+        let now = Instant::now();
+
+        let mut typed = match self.state.type_ahead.take() {
+            Some((typed, typed_at))
+                if now.duration_since(typed_at) < TYPE_AHEAD_TIMEOUT =>
+            {
+                typed
+            }
+            _ => String::new(),
+        };
+
+        typed.extend(c.to_lowercase());
+
+        let option = self
+            .options
+            .iter()
+            .find(|option| {
+                self.is_option_enabled(option)
+                    && option.to_string().to_lowercase().starts_with(&typed)
+            })
+            .cloned();
+
+        self.state.type_ahead = Some((typed, now));
+
+        if let Some(option) = option {
+            if self.selected.as_ref() != Some(&option) {
+                self.selected = Some(option.clone());
+
+                shell.publish((self.on_selected)(option));
+            }
+        }
+    }
+
+    /// Picks the given option from the menu, toggling it if
+    /// [`multi_select`](Self::multi_select) is enabled or selecting it
+    /// otherwise.
+    fn pick(&mut self, option: T, shell: &mut Shell<'_, Message>) {
+        if !self.multi_select {
+            self.select(option, shell);
+
+            return;
+        }
+
+        let selected_options = &mut self.state.selected_options;
+
+        let is_selected = match selected_options
+            .iter()
+            .position(|selected| *selected == option)
+        {
+            Some(index) => {
+                let _ = selected_options.remove(index);
+
+                false
+            }
+            None => {
+                selected_options.push(option.clone());
+
+                true
+            }
+        };
+
+        if self.defer_selection_until_close {
+            self.state.has_deferred_toggles = true;
+        } else if let Some(on_toggle) = &self.on_toggle {
+            shell.publish(on_toggle(option, is_selected));
+        }
+    }
+
+    /// Selects the given option, closing the menu and unfocusing the
+    /// [`SearchablePickList`].
+    ///
+    /// The text is replaced by the label of the option if
+    /// [`sync_text_on_select`](Self::sync_text_on_select) is enabled.
+    fn select(&mut self, option: T, shell: &mut Shell<'_, Message>) {
+        if self.sync_text_on_select {
+            self.replace_text(option.to_string(), shell);
+        }
+
+        let is_unchanged = self.selected.as_ref() == Some(&option);
+
+        self.selected = Some(option.clone());
+        self.state.is_focused = false;
+
+        if !(is_unchanged && self.skip_if_unchanged) {
+            shell.publish((self.on_selected)(option));
+        }
+
+        self.close_menu(shell);
+    }
+
+    /// Replaces the text of the [`SearchablePickList`], reporting it
+    /// through `on_change` right away if it differs.
+    fn replace_text(&mut self, text: String, shell: &mut Shell<'_, Message>) {
+        if text == self.value.to_string() {
+            return;
+        }
+
+        self.state.history.record(&self.value, self.state.cursor);
+        self.value = Value::new(&text);
+        self.state.cursor.move_to(self.value.len());
+        self.state.pending_change = None;
+
+        shell.publish((self.on_change)(text));
+    }
+
+    /// Closes the menu and unfocuses the [`SearchablePickList`], dropping
+    /// any interaction in progress.
+    fn release_focus(&mut self, shell: &mut Shell<'_, Message>) {
+        self.close_menu(shell);
+
+        self.state.is_focused = false;
+        self.state.is_dragging = false;
+        self.state.drag_origin = None;
+        self.state.is_pasting = None;
+
+        self.state.keyboard_modifiers = keyboard::Modifiers::default();
+    }
+
+    /// Returns whether the text of the [`SearchablePickList`] can be
+    /// committed as a custom value.
+    fn is_custom_value(&self) -> bool {
+        if !self.allow_custom || self.on_custom.is_none() {
+            return false;
+        }
+
+        let text = self.value.to_string();
+
+        !text.is_empty()
+            && self
+                .visible_options()
+                .iter()
+                .all(|option| option.to_string() != text)
+    }
+
+    /// Commits the text of the [`SearchablePickList`] as a custom value,
+    /// clearing the selection and closing the menu.
+    fn commit_custom_value(&mut self, shell: &mut Shell<'_, Message>) {
+        if let Some(on_custom) = &self.on_custom {
+            shell.publish(on_custom(self.value.to_string()));
+        }
+
+        self.selected = None;
+
+        self.close_menu(shell);
+    }
+
+    fn update(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        measurements: &mut Measurements<'_, Renderer>,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // The hover is tracked even when disabled or read-only, so the full
+        // label can still be revealed
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.state.is_hovered = field_bounds(layout, self.padding)
+                    .contains(cursor_position);
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.state.is_hovered = false;
+            }
+            _ => {}
+        }
+
+        if !self.is_enabled || self.is_read_only {
+            self.close_menu(shell);
+            self.state.is_focused = false;
+
+            return event::Status::Ignored;
+        }
+
+        if self.loading {
+            let _ = self.state.loading_since.get_or_insert_with(Instant::now);
+        } else {
+            self.state.loading_since = None;
+        }
+
+        if self.state.is_opening {
+            self.prepare_menu();
+        }
+
+        self.flush_change(Instant::now(), shell);
+        self.notify_hovered_option(shell);
+
+        let bounds = field_bounds(layout, self.padding);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) if cfg!(target_os = "linux")
+                && self.editable
+                && bounds.contains(cursor_position)
+                && !arrow_bounds(
+                    bounds,
+                    self.padding,
+                    self.arrow_reserved_width,
+                    self.is_right_to_left(),
+                )
+                .contains(cursor_position) =>
+            {
+                // Paste the primary selection where the field was clicked,
+                // like text inputs on X11 and Wayland do
+                let content = flatten(
+                    &clipboard
+                        .read_primary()
+                        .or_else(|| clipboard.read())
+                        .unwrap_or_default(),
+                );
+
+                let text_layout = layout.children().next().unwrap();
+                let target = cursor_position.x - text_layout.bounds().x;
+                let offset = self.text_offset(measurements, layout);
+
+                self.state.is_focused = true;
+                self.state
+                    .cursor
+                    .move_to(measurements.cursor_position(offset, target));
+
+                let content = content.until(self.remaining_length());
+
+                self.state.history.record(&self.value, self.state.cursor);
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut self.state.cursor);
+
+                editor.paste(content);
+
+                let contents = editor.contents();
+                self.publish_change(contents, shell);
+
+                self.open_menu(shell);
+                self.value_changed(shell);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_clicked = bounds.contains(cursor_position);
+
+                if is_clicked
+                    && self.shows_clear_icon()
+                    && clear_icon_bounds(
+                        bounds,
+                        self.padding,
+                        self.arrow_reserved_width,
+                        self.is_right_to_left(),
+                    )
+                    .contains(cursor_position)
+                {
+                    self.clear(shell);
+
+                    return event::Status::Captured;
+                }
+
+                // TODO: Encode cursor availability in the type system
+                let is_over_menu = self.is_menu_visible()
+                    && (cursor_position.x < 0.0 || cursor_position.y < 0.0);
+
+                let event_status = if is_clicked {
+                    self.state.is_previewing = false;
+
+                    if !self.state.pick_list.is_open {
+                        if !self.state.is_focused {
+                            // Arm the selection for the click that follows
+                            self.state.first_click =
+                                self.select_all_first_click;
+                        }
+
+                        self.state.is_focused = true;
+
+                        self.open_menu(shell);
+
+                        event::Status::Captured
+                    } else {
+                        if arrow_bounds(
+                            bounds,
+                            self.padding,
+                            self.arrow_reserved_width,
+                            self.is_right_to_left(),
+                        )
+                        .contains(cursor_position)
+                        {
+                            self.close_menu(shell);
+                            self.state.is_focused = false;
+                            self.state.first_click = false;
+
+                            event::Status::Captured
+                        } else {
+                            // Otherwise the user must have clicked inside the text field
+                            self.state.is_focused = true;
+                            self.state.history.seal();
+
+                            let text_layout = layout.children().next().unwrap();
+                            let target =
+                                cursor_position.x - text_layout.bounds().x;
+
+                            let click = mouse::Click::new(
+                                cursor_position,
+                                self.state.last_click,
+                            );
+
+                            match click.kind() {
+                                click::Kind::Single => {
+                                    if target > 0.0 {
+                                        let value = self.displayed_value();
+
+                                        if self.select_all_first_click
+                                            && self.state.first_click
+                                        {
+                                            self.state
+                                                .cursor
+                                                .select_all(&value);
+                                            self.state.first_click = false;
+                                        } else {
+                                            let offset = self.text_offset(
+                                                measurements,
+                                                layout,
+                                            );
+
+                                            let position = measurements
+                                                .cursor_position(
+                                                    offset, target,
+                                                );
+
+                                            self.state.cursor.move_to(position);
+
+                                            self.state.drag_origin =
+                                                Some(cursor_position);
+                                        }
+                                    } else {
+                                        self.state.cursor.move_to(0);
+
+                                        self.state.drag_origin =
+                                            Some(cursor_position);
+                                    }
+                                }
+                                click::Kind::Double => {
+                                    if self.is_secure {
+                                        self.state
+                                            .cursor
+                                            .select_all(&self.value);
+                                    } else {
+                                        let offset = self
+                                            .text_offset(measurements, layout);
+
+                                        let position = measurements
+                                            .cursor_position(offset, target);
+
+                                        self.state.cursor.select_range(
+                                            self.value.previous_start_of_word(
+                                                position,
+                                            ),
+                                            self.value
+                                                .next_end_of_word(position),
+                                        );
+                                    }
+
+                                    self.state.is_dragging = false;
+                                }
+                                click::Kind::Triple => {
+                                    self.state.cursor.select_all(&self.value);
+                                    self.state.is_dragging = false;
+                                }
+                            }
+
+                            self.state.last_click = Some(click);
+
+                            event::Status::Captured
+                        }
+                    }
+                } else if is_over_menu
+                    || self
+                        .ignored_outside_clicks
+                        .iter()
+                        .any(|region| region.contains(cursor_position))
+                {
+                    // Presses over the menu, like dragging its scrollbar,
+                    // must not close it. Selections are handled below.
+                    event::Status::Ignored
+                } else {
+                    self.close_menu(shell);
+                    self.state.is_focused = false;
+                    self.state.first_click = false;
+
+                    event::Status::Ignored
+                };
+
+                if let Some(last_selection) =
+                    self.state.pick_list.last_selection.take()
+                {
+                    self.pick(last_selection, shell);
+
+                    return event::Status::Captured;
+                } else {
+                    return event_status;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.is_dragging = false;
+                self.state.drag_origin = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if self.open_on_hover && !self.state.is_focused {
+                    // TODO: Encode cursor availability in the type system
+                    let is_over_menu =
+                        cursor_position.x < 0.0 || cursor_position.y < 0.0;
+
+                    if bounds.contains(cursor_position) {
+                        let delay = self.hover_open_delay;
+                        let hover_open_at = *self
+                            .state
+                            .hover_open_at
+                            .get_or_insert_with(|| Instant::now() + delay);
+
+                        if !self.state.pick_list.is_open
+                            && hover_open_at <= Instant::now()
+                        {
+                            self.open_menu(shell);
+                            self.state.is_previewing = true;
+                        }
+                    } else {
+                        self.state.hover_open_at = None;
+
+                        if self.state.is_previewing && !is_over_menu {
+                            self.close_menu(shell);
+                            self.state.is_previewing = false;
+                        }
+                    }
+                }
+
+                if let Some(origin) = self.state.drag_origin {
+                    if origin.distance(position) > self.drag_threshold {
+                        self.state.is_dragging = true;
+                        self.state.drag_origin = None;
+                    }
+                }
+
+                if self.state.is_dragging {
+                    let text_layout = layout.children().next().unwrap();
+                    let target = position.x - text_layout.bounds().x;
+
+                    if target > 0.0 {
+                        let value = self.displayed_value();
+
+                        let offset = self.text_offset(measurements, layout);
+                        let position =
+                            measurements.cursor_position(offset, target);
+
+                        self.state.cursor.select_range(
+                            self.state.cursor.start(&value),
+                            position,
+                        );
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.state.hover_open_at = None;
+
+                if self.state.is_previewing {
+                    self.close_menu(shell);
+                    self.state.is_previewing = false;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta:
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. },
+            }) if self.wheel_cycle
+                && !self.state.pick_list.is_open
+                && y != 0.0
+                && bounds.contains(cursor_position) =>
+            {
+                self.select_adjacent(y < 0.0, shell);
+
+                return event::Status::Captured;
+            }
+            // Typed control characters, like line breaks and tabs, come from
+            // keys with their own meaning, so they are never inserted
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.type_ahead
+                    && self.state.is_focused
+                    && !self.state.pick_list.is_open
+                    && !self.state.keyboard_modifiers.command()
+                    && !c.is_control() =>
+            {
+                self.select_typed_ahead(c, shell);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.state.is_focused
+                    && self.editable
+                    && self.state.is_pasting.is_none()
+                    && !self.state.keyboard_modifiers.command()
+                    && !c.is_control() =>
+            {
+                if self.remaining_length() == 0 {
+                    return event::Status::Captured;
+                }
+
+                self.state
+                    .history
+                    .record_insert(&self.value, self.state.cursor);
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut self.state.cursor);
+
+                editor.insert(c);
+
+                let contents = editor.contents();
+                self.publish_change(contents, shell);
+
+                self.value_changed(shell);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                let modifiers = self.state.keyboard_modifiers;
+
+                if let Some(on_key) = &self.on_key {
+                    if let Some(message) = on_key(key_code, modifiers) {
+                        shell.publish(message);
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if !self.editable {
+                    if let keyboard::KeyCode::Backspace
+                    | keyboard::KeyCode::Delete = key_code
+                    {
+                        if self.selected.take().is_some() {
+                            if let Some(on_clear) = self.on_clear.clone() {
+                                shell.publish(on_clear);
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                match key_code {
+                    keyboard::KeyCode::Enter => {
+                        if let Some(option) = self.hovered_option() {
+                            self.pick(option, shell);
+                        } else if self.is_custom_value() {
+                            self.commit_custom_value(shell);
+                        } else if let Some(on_submit) = self.on_submit.clone() {
+                            shell.publish(on_submit);
+                        }
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && self
+                                .state
+                                .cursor
+                                .selection(&self.value)
+                                .is_none()
+                        {
+                            if self.is_secure {
+                                let cursor_pos =
+                                    self.state.cursor.end(&self.value);
+                                self.state.cursor.select_range(0, cursor_pos);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .select_left_by_words(&self.value);
+                            }
+                        }
+
+                        self.state
+                            .history
+                            .record(&self.value, self.state.cursor);
+
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.backspace();
+
+                        let contents = editor.contents();
+                        self.publish_change(contents, shell);
+
+                        self.value_changed(shell);
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && self
+                                .state
+                                .cursor
+                                .selection(&self.value)
+                                .is_none()
+                        {
+                            if self.is_secure {
+                                let cursor_pos =
+                                    self.state.cursor.end(&self.value);
+                                self.state
+                                    .cursor
+                                    .select_range(cursor_pos, self.value.len());
+                            } else {
+                                self.state
+                                    .cursor
+                                    .select_right_by_words(&self.value);
+                            }
+                        }
+
+                        self.state
+                            .history
+                            .record(&self.value, self.state.cursor);
+
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.delete();
+
+                        let contents = editor.contents();
+                        self.publish_change(contents, shell);
+
+                        self.value_changed(shell);
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down
+                        if self.is_menu_visible() =>
+                    {
+                        self.hover_next_option(
+                            key_code == keyboard::KeyCode::Down,
+                        );
+                    }
+                    keyboard::KeyCode::Down
+                        if !self.state.pick_list.is_open =>
+                    {
+                        self.open_menu(shell);
+                    }
+                    keyboard::KeyCode::Space
+                        if !self.editable && !self.state.pick_list.is_open =>
+                    {
+                        self.open_menu(shell);
+                    }
+                    keyboard::KeyCode::PageUp | keyboard::KeyCode::PageDown
+                        if self.is_menu_visible() =>
+                    {
+                        let text_size =
+                            self.menu_text_size.or(self.size).unwrap_or_else(
+                                || measurements.renderer.default_size(),
+                            );
+
+                        self.hover_next_page(
+                            key_code == keyboard::KeyCode::PageDown,
+                            text_size,
+                        );
+                    }
+                    keyboard::KeyCode::Home if self.is_menu_visible() => {
+                        self.hover_enabled_option(0, true);
+                    }
+                    keyboard::KeyCode::End if self.is_menu_visible() => {
+                        self.hover_enabled_option(usize::MAX, false);
+                    }
+                    keyboard::KeyCode::Left => {
+                        self.state.history.seal();
+
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && !self.is_secure
+                        {
+                            if modifiers.shift() {
+                                self.state
+                                    .cursor
+                                    .select_left_by_words(&self.value);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .move_left_by_words(&self.value);
+                            }
+                        } else if modifiers.shift() {
+                            self.state.cursor.select_left(&self.value)
+                        } else {
+                            self.state.cursor.move_left(&self.value);
+                        }
+                    }
+                    keyboard::KeyCode::Right => {
+                        self.state.history.seal();
+
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && !self.is_secure
+                        {
+                            if modifiers.shift() {
+                                self.state
+                                    .cursor
+                                    .select_right_by_words(&self.value);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .move_right_by_words(&self.value);
+                            }
+                        } else if modifiers.shift() {
+                            self.state.cursor.select_right(&self.value)
+                        } else {
+                            self.state.cursor.move_right(&self.value);
+                        }
+                    }
+                    keyboard::KeyCode::Home => {
+                        self.state.history.seal();
+
+                        if modifiers.shift() {
+                            self.state.cursor.select_range(
+                                self.state.cursor.start(&self.value),
+                                0,
+                            );
+                        } else {
+                            self.state.cursor.move_to(0);
+                        }
+                    }
+                    keyboard::KeyCode::End => {
+                        self.state.history.seal();
+
+                        if modifiers.shift() {
+                            self.state.cursor.select_range(
+                                self.state.cursor.start(&self.value),
+                                self.value.len(),
+                            );
+                        } else {
+                            self.state.cursor.move_to(self.value.len());
+                        }
+                    }
+                    keyboard::KeyCode::C
+                        if self.state.keyboard_modifiers.command() =>
+                    {
+                        self.select_whole_line_if_unselected();
+
+                        match self.state.cursor.selection(&self.value) {
+                            Some((start, end)) if !self.is_secure => {
+                                clipboard.write(
+                                    self.value.select(start, end).to_string(),
+                                );
+                            }
+                            _ => {}
+                        }
+                    }
+                    keyboard::KeyCode::X
+                        if self.state.keyboard_modifiers.command()
+                            && self.editable =>
+                    {
+                        self.select_whole_line_if_unselected();
+
+                        match self.state.cursor.selection(&self.value) {
+                            Some((start, end)) if !self.is_secure => {
+                                clipboard.write(
+                                    self.value.select(start, end).to_string(),
+                                );
+                            }
+                            _ => {}
+                        }
+
+                        self.state
+                            .history
+                            .record(&self.value, self.state.cursor);
+
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.delete();
+
+                        let contents = editor.contents();
+                        self.publish_change(contents, shell);
+
+                        self.value_changed(shell);
+                    }
+                    keyboard::KeyCode::V if self.editable => {
+                        if self.state.keyboard_modifiers.command() {
+                            let content = match self.state.is_pasting.take() {
+                                Some(content) => content,
+                                None => flatten(
+                                    &clipboard.read().unwrap_or_default(),
+                                )
+                                .until(self.remaining_length()),
+                            };
+
+                            self.state
+                                .history
+                                .record(&self.value, self.state.cursor);
+
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut self.state.cursor,
+                            );
+
+                            editor.paste(content.clone());
+
+                            let contents = editor.contents();
+                            self.publish_change(contents, shell);
+
+                            self.value_changed(shell);
+
+                            self.state.is_pasting = Some(content);
+                        } else {
+                            self.state.is_pasting = None;
+                        }
+                    }
+                    keyboard::KeyCode::Z | keyboard::KeyCode::Y
+                        if self.state.keyboard_modifiers.command()
+                            && self.editable =>
+                    {
+                        let is_redo = key_code == keyboard::KeyCode::Y
+                            || self.state.keyboard_modifiers.shift();
+
+                        let is_changed = if is_redo {
+                            self.state
+                                .history
+                                .redo(&mut self.value, &mut self.state.cursor)
+                        } else {
+                            self.state
+                                .history
+                                .undo(&mut self.value, &mut self.state.cursor)
+                        };
+
+                        if is_changed {
+                            self.publish_change(self.value.to_string(), shell);
+
+                            self.value_changed(shell);
+                        }
+                    }
+                    keyboard::KeyCode::A
+                        if self.state.keyboard_modifiers.command() =>
+                    {
+                        self.state.cursor.select_all(&self.value);
+                    }
+                    keyboard::KeyCode::Escape => {
+                        if self.restore_on_escape {
+                            let text = self
+                                .selected
+                                .as_ref()
+                                .map(ToString::to_string)
+                                .unwrap_or_default();
+
+                            self.replace_text(text, shell);
+                        }
+
+                        self.release_focus(shell);
+                    }
+                    keyboard::KeyCode::Tab => {
+                        self.release_focus(shell);
+
+                        // Let the surrounding widgets move the focus along
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code, ..
+            }) if self.state.is_focused => {
+                match key_code {
+                    keyboard::KeyCode::V => {
+                        self.state.is_pasting = None;
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers))
+                if self.state.is_focused =>
+            {
+                self.state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for SearchablePickList<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: Clone,
+    Renderer: text::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let limits = limits
+            .pad(self.padding)
+            .width(self.width)
+            .max_width(self.max_width);
+
+        let is_open = self.is_open();
+
+        let text_height = if self.wrap && !is_open {
+            let label = self
+                .closed_label()
+                .unwrap_or_else(|| self.placeholder().to_owned());
+
+            let max_width = limits.resolve(Size::ZERO).width
+                - self.affix_width(renderer, self.prefix.as_deref())
+                - self.trailing_width(renderer);
+
+            let (_, height) = renderer.measure(
+                &label,
+                text_size,
+                self.font.clone(),
+                Size::new(max_width, f32::INFINITY),
+            );
+
+            (height.ceil() as u16).max(text_size)
+        } else {
+            text_size
+        };
+
+        let limits = limits.height(Length::Units(text_height));
+
+        let size = limits.resolve(Size::ZERO);
+        let field = size.pad(self.padding);
+
+        // The editable text starts right after the prefix
+        let prefix_width = self
+            .affix_width(renderer, self.prefix.as_deref())
+            .min(size.width);
+
+        let (x, width) = if self.is_right_to_left() {
+            let trailing_width =
+                self.trailing_width(renderer).min(size.width - prefix_width);
+
+            (trailing_width, size.width - prefix_width - trailing_width)
+        } else {
+            (prefix_width, size.width - prefix_width)
+        };
+
+        let mut text = layout::Node::new(Size::new(width, size.height));
+        text.move_to(Point::new(
+            f32::from(self.padding.left) + x,
+            self.padding.top.into(),
+        ));
+
+        if self.helper_text.is_none() && self.error_text.is_none() {
+            return layout::Node::with_children(field, vec![text]);
+        }
+
+        let message_size = f32::from(text_size) * 0.75;
+
+        let mut message =
+            layout::Node::new(Size::new(size.width, message_size));
+        message.move_to(Point::new(
+            self.padding.left.into(),
+            field.height + MESSAGE_SPACING,
+        ));
+
+        layout::Node::with_children(
+            Size::new(
+                field.width,
+                field.height + MESSAGE_SPACING + message_size,
+            ),
+            vec![text, message],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let was_focused = self.state.is_focused;
+        let caret = caret_position(self.state.cursor, &self.value);
+
+        let mut measurements = Measurements::new(
+            renderer,
+            self.font.clone(),
+            self.size.unwrap_or(renderer.default_size()),
+            &self.displayed_value(),
+        );
+
+        let status = self.update(
+            event,
+            layout,
+            cursor_position,
+            &mut measurements,
+            clipboard,
+            shell,
+        );
+
+        self.scroll_to_cursor(&mut measurements, layout);
+
+        self.state.value = self.value.clone();
+
+        // Editing shows the caret right away, restarting its blink
+        if !self.caret_blink {
+            self.state.caret_since = None;
+        } else if self.state.is_focused
+            && (!was_focused
+                || caret != caret_position(self.state.cursor, &self.value))
+        {
+            self.state.caret_since = Some(Instant::now());
+        }
+
+        if self.state.is_focused != was_focused {
+            let message = if self.state.is_focused {
+                self.on_focus.clone()
+            } else {
+                self.on_unfocus.clone()
+            };
+
+            if let Some(message) = message {
+                shell.publish(message);
+            }
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        self.draw(renderer, layout, cursor_position, None)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.state.is_opening {
+            self.prepare_menu();
+        }
+
+        if self.is_menu_visible() {
+            let bounds = field_bounds(layout, self.padding);
+
+            let options = if self.state.filtered_indices.is_some() {
+                &self.state.filtered_options[..]
+            } else {
+                &self.options[..]
+            };
+
+            let empty_message = if self.loading {
+                &self.loading_message
+            } else {
+                &self.options_empty_message
+            };
+
+            let right_to_left = self.is_right_to_left();
+
+            let max_height = self.effective_menu_max_height(
+                self.menu_text_size
+                    .or(self.size)
+                    .unwrap_or_else(|| renderer.default_size()),
+            );
+
+            let mut menu = Menu::new(
+                &mut self.state.pick_list.menu,
+                options,
+                empty_message,
+                &mut self.state.pick_list.hovered_option,
+                &mut self.state.pick_list.last_selection,
+            )
+            .width((bounds.width.round() as u16).max(self.menu_min_width))
+            .padding(self.padding)
+            .font(self.menu_font.clone().unwrap_or_else(|| self.font.clone()))
+            .center_empty_message(self.center_options_empty_message)
+            .right_to_left(right_to_left)
+            .empty_message_color(self.style_sheet.options_empty_message_color())
+            .style(self.style_sheet.menu());
+
+            if let Some(height) = self.options_empty_message_height {
+                menu = menu.empty_message_height(height);
+            }
+
+            if let Some(size) = self.menu_text_size.or(self.size) {
+                menu = menu.text_size(size);
+            }
+
+            if let Some(max_height) = max_height {
+                menu = menu.max_height(max_height);
+            }
+
+            let query = self.value.to_string();
+
+            if !query.is_empty() {
+                match self.match_highlight_mode {
+                    MatchHighlightMode::None => {}
+                    MatchHighlightMode::Row => {
+                        let background =
+                            self.style_sheet.match_highlight_background();
+
+                        menu = menu.option_background(move |option| {
+                            matches_query(option, &query).then(|| background)
+                        });
+                    }
+                    MatchHighlightMode::Span => {
+                        let color = self.style_sheet.matched_text_color();
+
+                        menu = menu.option_match(
+                            move |option| {
+                                match_range(&option.to_string(), &query)
+                            },
+                            color,
+                        );
+                    }
+                }
+            }
+
+            if let Some(option_color) = &self.option_color {
+                menu = menu.option_color(move |option| option_color(option));
+            }
+
+            if let Some(option_enabled) = &self.option_enabled {
+                menu =
+                    menu.option_enabled(move |option| option_enabled(option));
+            }
+
+            if self.multi_select {
+                let selected_options = &self.state.selected_options;
+
+                menu = menu.checkboxes(
+                    move |option| selected_options.contains(option),
+                    self.style_sheet.checkmark_color(),
+                );
+            } else if let Some(selected) = &self.selected {
+                menu = menu.selected_option(
+                    selected,
+                    self.style_sheet.checkmark_color(),
+                );
+            }
+
+            if let Some(group_by) = &self.group_by {
+                menu = menu
+                    .group_by(move |option| group_by(option))
+                    .group_header_color(self.style_sheet.group_header_color());
+            }
+
+            if let Some(match_score) = &self.match_score {
+                let query = self.value.to_string();
+
+                menu = menu.trailing_label(move |option| {
+                    format!("{:.2}", match_score(option, &query))
+                });
+            }
+
+            Some(menu.overlay(layout.position(), bounds.height))
+        } else {
+            let label = self.full_label(layout, renderer)?;
+            let bounds = field_bounds(layout, self.padding);
+
+            Some(overlay::Element::new(
+                layout.position(),
+                Box::new(FullLabel {
+                    label,
+                    font: self.font.clone(),
+                    size: self.size.unwrap_or_else(|| renderer.default_size()),
+                    padding: self.padding,
+                    target_height: bounds.height,
+                    style: self.style_sheet.menu(),
+                }),
+            ))
+        }
+    }
+}
+
+/// A tooltip showing the full label of the selected option of a closed
+/// [`SearchablePickList`].
+struct FullLabel<Renderer: text::Renderer> {
+    label: String,
+    font: Renderer::Font,
+    size: u16,
+    padding: Padding,
+    target_height: f32,
+    style: menu::Style,
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Renderer>
+    for FullLabel<Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let width =
+            renderer.measure_width(&self.label, self.size, self.font.clone());
+
+        let size = Size::new(
+            (width + f32::from(self.padding.horizontal()))
+                .min(bounds.width - position.x),
+            f32::from(self.size) + f32::from(self.padding.vertical()),
+        );
+
+        let mut node = layout::Node::new(size);
+
+        // Like the menu, the tooltip is only placed on top if it does not
+        // fit under the target
+        let space_below = bounds.height - (position.y + self.target_height);
+
+        node.move_to(if size.height <= space_below {
+            position + Vector::new(0.0, self.target_height)
+        } else {
+            position - Vector::new(0.0, size.height)
+        });
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_color: self.style.border_color,
+                border_width: self.style.border_width,
+                border_radius: 0.0,
+            },
+            self.style.background,
+        );
+
+        renderer.fill_text(Text {
+            content: &self.label,
+            size: f32::from(self.size),
+            font: self.font.clone(),
+            color: self.style.text_color,
+            bounds: Rectangle {
+                x: bounds.x + f32::from(self.padding.left),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> Into<Element<'a, Message, Renderer>>
+    for SearchablePickList<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer + 'a,
+    Message: 'a + Clone,
+{
+    fn into(self) -> Element<'a, Message, Renderer> {
+        Element::new(self)
+    }
+}
+
+/// The state of a [`SearchablePickList`].
+#[derive(Debug, Default, Clone)]
+pub struct State<T> {
+    pick_list: pick_list::State<T>,
+    is_opening: bool,
+    is_focused: bool,
+    is_dragging: bool,
+    drag_origin: Option<Point>,
+    is_pasting: Option<Value>,
+    last_click: Option<mouse::Click>,
+    cursor: Cursor,
+    scroll_offset: f32,
+    keyboard_modifiers: keyboard::Modifiers,
+    first_click: bool,
+    is_previewing: bool,
+    hover_open_at: Option<Instant>,
+    is_hovered: bool,
+    pending_hover: Option<(usize, T, Instant)>,
+    reported_hover: Option<usize>,
+    reported_filtered: Option<usize>,
+    filtered_count: Option<usize>,
+    filtered_indices: Option<Vec<usize>>,
+    filtered_options: Vec<T>,
+    history: History,
+    value: Value,
+    pending_change: Option<(Value, Instant)>,
+    loading_since: Option<Instant>,
+    caret_since: Option<Instant>,
+    type_ahead: Option<(String, Instant)>,
+    selected_options: Vec<T>,
+    has_deferred_toggles: bool,
+}
+
+impl<T: Default> State<T> {
+    /// Creates a new [`State`], representing an unfocused [`SearchablePickList`].
+    pub fn new() -> Self {
+        Self {
+            pick_list: pick_list::State::default(),
+            is_opening: false,
+            is_focused: false,
+            is_dragging: false,
+            drag_origin: None,
+            is_pasting: None,
+            last_click: None,
+            cursor: Cursor::default(),
+            scroll_offset: 0.0,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            first_click: false,
+            is_previewing: false,
+            hover_open_at: None,
+            is_hovered: false,
+            pending_hover: None,
+            reported_hover: None,
+            reported_filtered: None,
+            filtered_count: None,
+            filtered_indices: None,
+            filtered_options: Vec::new(),
+            history: History::new(),
+            value: Value::new(""),
+            pending_change: None,
+            loading_since: None,
+            caret_since: None,
+            type_ahead: None,
+            selected_options: Vec::new(),
+            has_deferred_toggles: false,
+        }
+    }
+
+    /// Creates a new [`State`], representing a focused [`SearchablePickList`].
+    pub fn focused() -> Self {
+        Self {
+            pick_list: pick_list::State::default(),
+            is_opening: false,
+            is_focused: true,
+            is_dragging: false,
+            drag_origin: None,
+            is_pasting: None,
+            last_click: None,
+            cursor: Cursor::default(),
+            scroll_offset: 0.0,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            first_click: false,
+            is_previewing: false,
+            hover_open_at: None,
+            is_hovered: false,
+            pending_hover: None,
+            reported_hover: None,
+            reported_filtered: None,
+            filtered_count: None,
+            filtered_indices: None,
+            filtered_options: Vec::new(),
+            history: History::new(),
+            value: Value::new(""),
+            pending_change: None,
+            loading_since: None,
+            caret_since: None,
+            type_ahead: None,
+            selected_options: Vec::new(),
+            has_deferred_toggles: false,
+        }
+    }
+
+    /// Returns whether the [`SearchablePickList`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the index of the option currently highlighted with the
+    /// keyboard in the menu of the [`SearchablePickList`], if any.
+    ///
+    /// The index refers to the options displayed in the menu, so it points
+    /// into the filtered options when filtering is active.
+    pub fn hovered_index(&self) -> Option<usize> {
+        self.pick_list.hovered_option
+    }
+
+    /// Returns the option picked in the menu of the [`SearchablePickList`]
+    /// that has not been selected yet, if any.
+    ///
+    /// It is only set for the duration of the event picking it.
+    pub fn last_selection(&self) -> Option<&T> {
+        self.pick_list.last_selection.as_ref()
+    }
+
+    /// Returns whether text of the [`SearchablePickList`] is currently being
+    /// selected by dragging.
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+
+    /// Returns the options toggled on in a [`SearchablePickList`] with
+    /// multi-select enabled, in the order they were selected.
+    pub fn selected_options(&self) -> &[T] {
+        &self.selected_options
+    }
+
+    /// Replaces the options toggled on in a [`SearchablePickList`] with
+    /// multi-select enabled.
+    pub fn set_selected_options(&mut self, options: Vec<T>) {
+        self.selected_options = options;
+    }
+
+    /// Returns the text of the [`SearchablePickList`] as of its last update.
+    ///
+    /// It includes edits that have not been reported through `on_change`
+    /// yet, like debounced ones.
+    pub fn value(&self) -> String {
+        self.value.to_string()
+    }
+
+    /// Replaces the text of the [`SearchablePickList`], moving the cursor to
+    /// its end.
+    ///
+    /// The text overrides the value passed to the [`SearchablePickList`]
+    /// until it is reported back to the application through `on_change`,
+    /// which happens the next time the [`SearchablePickList`] receives an
+    /// event, or as a [`Deferred::Changed`] update the next time it is
+    /// polled.
+    pub fn set_value(&mut self, value: &str) {
+        let value = Value::new(value);
+
+        self.cursor.move_to(value.len());
+        self.value = value.clone();
+        self.pending_change = Some((value, Instant::now()));
+    }
+
+    /// Returns the [`Cursor`] of the [`SearchablePickList`].
+    pub fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    /// Focuses the [`SearchablePickList`].
+    ///
+    /// The [`State`] is owned by the application, so it is also how a
+    /// specific [`SearchablePickList`] is focused programmatically, like
+    /// when the application starts:
+    ///
+    /// ```
+    /// # use iced_native::widget::searchable_pick_list::State;
+    /// let mut state = State::<String>::new();
+    ///
+    /// state.focus();
+    /// state.move_cursor_to_end();
+    ///
+    /// assert!(state.is_focused());
+    /// ```
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`SearchablePickList`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// Returns whether the menu of the [`SearchablePickList`] is open.
+    pub fn is_open(&self) -> bool {
+        self.pick_list.is_open
+    }
+
+    /// Opens the menu of the [`SearchablePickList`].
+    ///
+    /// The selected option is hovered the next time the
+    /// [`SearchablePickList`] is updated or its menu is displayed, like when
+    /// it is clicked. No `on_open` message is produced.
+    pub fn open(&mut self) {
+        self.is_opening = !self.pick_list.is_open;
+        self.pick_list.is_open = true;
+    }
+
+    /// Closes the menu of the [`SearchablePickList`].
+    ///
+    /// No `on_close` message is produced.
+    pub fn close(&mut self) {
+        self.is_opening = false;
+        self.pick_list.is_open = false;
+    }
+
+    /// Moves the [`Cursor`] of the [`SearchablePickList`] to the front of the input text.
+    pub fn move_cursor_to_front(&mut self) {
+        self.cursor.move_to(0);
+    }
+
+    /// Moves the [`Cursor`] of the [`SearchablePickList`] to the end of the input text.
+    pub fn move_cursor_to_end(&mut self) {
+        self.cursor.move_to(usize::MAX);
+    }
+
+    /// Moves the [`Cursor`] of the [`SearchablePickList`] to an arbitrary location.
+    pub fn move_cursor_to(&mut self, position: usize) {
+        self.cursor.move_to(position);
+    }
+
+    /// Selects all the content of the [`SearchablePickList`].
+    ///
+    /// The selection is only displayed while the [`SearchablePickList`] is
+    /// focused and open, since its closed label shows the selected option
+    /// instead of the text.
+    pub fn select_all(&mut self) {
+        self.cursor.select_range(0, usize::MAX);
+    }
+
+    /// Takes a [`Snapshot`] of the [`State`] for the given selected option.
+    ///
+    /// The selected option is owned by the application, so it needs to be
+    /// provided in order to be part of the [`Snapshot`]. The text value is
+    /// the one returned by [`State::value`].
+    pub fn snapshot(&self, selected: Option<&T>) -> Snapshot<T>
+    where
+        T: Clone,
+    {
+        Snapshot {
+            value: self.value.to_string(),
+            selection_start: self.cursor.start(&self.value),
+            selection_end: self.cursor.end(&self.value),
+            selected: selected.cloned(),
+            selected_options: self.selected_options.clone(),
+        }
+    }
+
+    /// Restores the text value, the cursor, the selection and the toggled
+    /// options of a [`Snapshot`].
+    ///
+    /// Transient state, like dragging or pasting, is reset. The
+    /// [`Snapshot::value`] and the [`Snapshot::selected`] option should
+    /// also be restored by the application and passed back to the
+    /// [`SearchablePickList`].
+    pub fn restore(&mut self, snapshot: &Snapshot<T>)
+    where
+        T: Clone,
+    {
+        self.value = Value::new(&snapshot.value);
+        self.cursor
+            .select_range(snapshot.selection_start, snapshot.selection_end);
+        self.selected_options = snapshot.selected_options.clone();
+
+        self.keyboard_modifiers = keyboard::Modifiers::default();
+        self.is_dragging = false;
+        self.drag_origin = None;
+        self.is_pasting = None;
+        self.last_click = None;
+        self.first_click = false;
+    }
+}
+
+impl<T: Clone> State<T> {
+    /// Returns whether the [`SearchablePickList`] is waiting for time to
+    /// pass, like for a debounced edit to be reported.
+    ///
+    /// The [`SearchablePickList`] only reacts to time when it receives an
+    /// event or is redrawn. While this returns `true`, the application should
+    /// call [`State::poll`] regularly, like from a `time::every`
+    /// subscription. Handling the tick also redraws the
+    /// [`SearchablePickList`], which keeps its caret blinking and its loading
+    /// spinner spinning.
+    pub fn needs_tick(&self) -> bool {
+        self.pending_change.is_some()
+            || self.pending_hover.is_some()
+            || self.hover_open_at.is_some()
+                && !self.is_focused
+                && !self.pick_list.is_open
+            || self.is_focused && self.caret_since.is_some()
+            || self.loading_since.is_some()
+    }
+
+    /// Takes the updates of the [`SearchablePickList`] that are due at the
+    /// given [`Instant`] and have not been produced by an event yet.
+    ///
+    /// The application should handle them like the messages they stand for:
+    ///
+    /// ```
+    /// # use iced_native::time::Instant;
+    /// # use iced_native::widget::searchable_pick_list::{Deferred, State};
+    /// # enum Message {
+    /// #     Changed(String),
+    /// #     Filtered(usize),
+    /// #     Hovered(String),
+    /// #     Opened,
+    /// # }
+    /// # let update = |_: Message| {};
+    /// let mut state = State::<String>::new();
+    ///
+    /// for deferred in state.poll(Instant::now()) {
+    ///     match deferred {
+    ///         Deferred::Changed(value) => update(Message::Changed(value)),
+    ///         Deferred::Filtered(count) => update(Message::Filtered(count)),
+    ///         Deferred::Hovered(option) => update(Message::Hovered(option)),
+    ///         Deferred::Opened => update(Message::Opened),
+    ///     }
+    /// }
+    /// ```
+    pub fn poll(&mut self, now: Instant) -> Vec<Deferred<T>> {
+        let mut deferred = Vec::new();
+
+        if let Some(value) = self.take_due_change(now) {
+            deferred.push(Deferred::Changed(value.to_string()));
+
+            if let Some(count) = self.take_unreported_filtered() {
+                deferred.push(Deferred::Filtered(count));
+            }
+        }
+
+        if let Some(option) = self.take_due_hover(now) {
+            deferred.push(Deferred::Hovered(option));
+        }
+
+        let is_hover_due = self.hover_open_at.map_or(false, |due| due <= now);
+
+        if is_hover_due && !self.is_focused && !self.pick_list.is_open {
+            self.is_opening = true;
+            self.is_previewing = true;
+            self.pick_list.is_open = true;
+
+            deferred.push(Deferred::Opened);
+        }
+
+        deferred
+    }
+
+    /// Takes the debounced edit of the [`SearchablePickList`], if it is due
+    /// at the given [`Instant`].
+    fn take_due_change(&mut self, now: Instant) -> Option<Value> {
+        match &self.pending_change {
+            Some((_, due)) if *due <= now => {
+                self.pending_change.take().map(|(value, _)| value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Takes the amount of options displayed in the menu of the
+    /// [`SearchablePickList`], if it changed since it was last reported.
+    fn take_unreported_filtered(&mut self) -> Option<usize> {
+        let count = self.filtered_count?;
+
+        if self.reported_filtered == Some(count) {
+            return None;
+        }
+
+        self.reported_filtered = Some(count);
+
+        Some(count)
+    }
+
+    /// Takes the option hovered in the menu of the [`SearchablePickList`],
+    /// if it has stayed hovered until the given [`Instant`].
+    fn take_due_hover(&mut self, now: Instant) -> Option<T> {
+        match &self.pending_hover {
+            Some((index, _, due)) if *due <= now => {
+                self.reported_hover = Some(*index);
+
+                self.pending_hover.take().map(|(_, option, _)| option)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A snapshot of the persistent parts of the [`State`] of a
+/// [`SearchablePickList`].
+///
+/// Only the text value, the cursor position, the selection and the selected
+/// options round-trip. Transient state, like dragging, pasting or clicks, is
+/// not persisted and is reset by [`State::restore`].
+///
+/// The pressed keyboard modifiers are not persisted either, since the keys
+/// may be released while the application is not running. They are reset too,
+/// so a [`SearchablePickList`] restored while Shift is held only extends a
+/// selection once the modifiers change again.
+///
+/// It can be serialized when the `serde` feature is enabled and the options
+/// can be serialized too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot<T = ()> {
+    /// The text value of the [`SearchablePickList`].
+    pub value: String,
+    /// The start of the selection, or the cursor position if nothing is
+    /// selected.
+    pub selection_start: usize,
+    /// The end of the selection, or the cursor position if nothing is
+    /// selected.
+    pub selection_end: usize,
+    /// The selected option of the [`SearchablePickList`].
+    ///
+    /// It is owned by the application rather than the [`State`], so it is
+    /// passed to [`State::snapshot`] and [`State::restore`] leaves it out.
+    /// The application should read it back from here and pass it to the
+    /// [`SearchablePickList`] again.
+    pub selected: Option<T>,
+    /// The options toggled on in a [`SearchablePickList`] with multi-select
+    /// enabled.
+    #[cfg_attr(feature = "serde", serde(default = "Vec::new"))]
+    pub selected_options: Vec<T>,
+}
+
+impl<T> Default for Snapshot<T> {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            selection_start: 0,
+            selection_end: 0,
+            selected: None,
+            selected_options: Vec::new(),
+        }
+    }
+}
+
+/// An update of a [`SearchablePickList`] that became due while it was not
+/// receiving events, returned by [`State::poll`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Deferred<T> {
+    /// The debounced text of the [`SearchablePickList`] changed, standing
+    /// for its `on_change` message.
+    Changed(String),
+    /// The debounced text changed the amount of options displayed in the
+    /// menu of the [`SearchablePickList`], standing for its `on_filtered`
+    /// message.
+    Filtered(usize),
+    /// An option stayed hovered in the menu of the [`SearchablePickList`],
+    /// standing for its `on_hover_option` message.
+    Hovered(T),
+    /// The menu of the [`SearchablePickList`] was opened by hovering it,
+    /// standing for its `on_open` message.
+    Opened,
+}
+
+/// The direction in which a [`SearchablePickList`] is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutDirection {
+    /// The arrow is at the right and the text starts at the left.
+    LeftToRight,
+    /// The arrow is at the left and the text starts at the right, for
+    /// right-to-left scripts like Arabic or Hebrew.
+    RightToLeft,
+}
+
+impl Default for LayoutDirection {
+    fn default() -> Self {
+        LayoutDirection::LeftToRight
+    }
+}
+
+/// The way the options matching the text of a [`SearchablePickList`] are
+/// highlighted in its menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchHighlightMode {
+    /// Matching options are not highlighted.
+    None,
+    /// The whole row of every matching option is tinted with the
+    /// [`StyleSheet::match_highlight_background`].
+    Row,
+    /// The part of every matching option that matches the text is displayed
+    /// with the [`StyleSheet::matched_text_color`].
+    Span,
+}
+
+impl Default for MatchHighlightMode {
+    fn default() -> Self {
+        MatchHighlightMode::Span
+    }
+}
+
+/// Computes the position of the text cursor at the given X coordinate of
+/// a [`SearchablePickList`].
+///
+/// The text of a focused [`SearchablePickList`] is assumed to be scrolled
+/// just enough to keep the [`Cursor`] visible. Use
+/// [`find_cursor_position_at_offset`] when the scroll offset is known.
+pub fn find_cursor_position<Renderer: text::Renderer>(
+    renderer: &Renderer,
+    text_bounds: Rectangle,
+    font: Renderer::Font,
+    size: Option<u16>,
+    value: &Value,
+    is_focused: bool,
+    cursor: Cursor,
+    x: f32,
+) -> usize {
+    let size = size.unwrap_or(renderer.default_size());
+    let mut measurements = Measurements::new(renderer, font, size, value);
+
+    let offset = if is_focused {
+        let focus_position = match cursor.state(value) {
+            cursor::State::Index(i) => i,
+            cursor::State::Selection { end, .. } => end,
+        };
+
+        (measurements.width(focus_position) + 5.0 - text_bounds.width).max(0.0)
+    } else {
+        0.0
+    };
+
+    measurements.cursor_position(offset, x)
+}
+
+/// Computes the position of the text cursor at the given X coordinate of
+/// a [`SearchablePickList`] whose text is scrolled by the given offset.
+pub fn find_cursor_position_at_offset<Renderer: text::Renderer>(
+    renderer: &Renderer,
+    font: Renderer::Font,
+    size: Option<u16>,
+    value: &Value,
+    offset: f32,
+    x: f32,
+) -> usize {
+    let size = size.unwrap_or(renderer.default_size());
+
+    Measurements::new(renderer, font, size, value).cursor_position(offset, x)
+}
+
+/// The widths of the prefixes of a [`Value`], measured while a
+/// [`SearchablePickList`] processes an event or is drawn.
+///
+/// All the widths share the same font and size, so they are only keyed by the
+/// length of the prefix. They are discarded when the [`Value`] changes.
+struct Measurements<'b, Renderer: text::Renderer> {
+    renderer: &'b Renderer,
+    font: Renderer::Font,
+    size: u16,
+    value: Value,
+    text: String,
+    widths: Vec<Option<f32>>,
+}
+
+impl<'b, Renderer: text::Renderer> Measurements<'b, Renderer> {
+    fn new(
+        renderer: &'b Renderer,
+        font: Renderer::Font,
+        size: u16,
+        value: &Value,
+    ) -> Self {
+        Self {
+            renderer,
+            font,
+            size,
+            value: value.clone(),
+            text: value.to_string(),
+            widths: vec![None; value.len() + 1],
+        }
+    }
+
+    /// Sets the [`Value`] being measured, discarding the widths measured so
+    /// far if it changed.
+    fn set_value(&mut self, value: &Value) {
+        if self.value != *value {
+            *self =
+                Self::new(self.renderer, self.font.clone(), self.size, value);
+        }
+    }
+
+    /// Returns the width of the graphemes until the given `index`.
+    fn width(&mut self, index: usize) -> f32 {
+        let index = index.min(self.value.len());
+
+        if let Some(width) = self.widths[index] {
+            return width;
+        }
+
+        let (width, _) = self.renderer.measure(
+            &self.text[..self.value.byte_offset(index)],
+            self.size,
+            self.font.clone(),
+            Size::INFINITY,
+        );
+
+        self.widths[index] = Some(width);
+
+        width
+    }
+
+    /// Returns the width of the whole [`Value`].
+    fn text_width(&mut self) -> f32 {
+        self.width(self.value.len())
+    }
+
+    /// Returns the position of the text cursor nearest to the given X
+    /// coordinate of the text scrolled by the given offset.
+    fn cursor_position(&mut self, offset: f32, x: f32) -> usize {
+        let target = x + offset;
+
+        let mut start = 0;
+        let mut end = self.value.len();
+
+        while start < end {
+            let index = start + (end - start) / 2;
+
+            if self.width(index).round() > target {
+                end = index;
+            } else {
+                start = index + 1;
+            }
+        }
+
+        if start == 0 {
+            return 0;
+        }
+
+        let prev_width = self.width(start - 1).round();
+        let next_width = self.width(start).round();
+
+        if next_width - target > target - prev_width {
+            start - 1
+        } else {
+            start
+        }
+    }
+}
+
+mod platform {
+    use crate::keyboard;
+
+    pub fn is_jump_modifier_pressed(modifiers: keyboard::Modifiers) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.alt()
+        } else {
+            modifiers.control()
+        }
+    }
+}
+
+/// The appearance of the field of a [`SearchablePickList`], gathered from
+/// the widget when it is drawn.
+struct Field<'b, T, Renderer: text::Renderer> {
+    pick_list_is_open: bool,
+    is_enabled: bool,
+    selected: Option<&'b T>,
+    selected_color: Option<Color>,
+    font: &'b Renderer::Font,
+    text_size: Option<u16>,
+    placeholder: &'b str,
+    is_placeholder_disabled: bool,
+    prefix: Option<&'b str>,
+    suffix: Option<&'b str>,
+    padding: Padding,
+    arrow_width: f32,
+    right_to_left: bool,
+    collapsed_alignment: alignment::Horizontal,
+    text_alignment: alignment::Horizontal,
+    wrap: bool,
+    value: &'b Value,
+    is_secure: bool,
+    is_focused: bool,
+    cursor: text_input_shared::cursor::Cursor,
+    is_caret_visible: bool,
+    scroll_offset: f32,
+    selection_opacity: f32,
+    underline: bool,
+    spinner: Option<char>,
+    icon: Option<(char, &'b Renderer::Font)>,
+    show_arrow: bool,
+    show_clear_icon: bool,
+    style_sheet: &'b dyn StyleSheet,
+}
+
+/// Draws the field of a [`SearchablePickList`] with the given bounds and
+/// the bounds of its text.
+///
+/// The text is edited in place while the menu is open. Otherwise, the
+/// selected option, or the placeholder, is displayed as a label.
+fn draw<T, Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    mut text_bounds: Rectangle,
+    cursor_position: Point,
+    field: Field<'_, T, Renderer>,
+) where
+    Renderer: text::Renderer,
+    T: ToString,
+{
+    let Field {
+        pick_list_is_open,
+        is_enabled,
+        selected,
+        selected_color,
+        font,
+        text_size,
+        placeholder,
+        is_placeholder_disabled,
+        prefix,
+        suffix,
+        padding,
+        arrow_width,
+        right_to_left,
+        collapsed_alignment,
+        text_alignment,
+        wrap,
+        value,
+        is_secure,
+        is_focused,
+        cursor,
+        is_caret_visible,
+        scroll_offset,
+        selection_opacity,
+        underline,
+        spinner,
+        icon,
+        show_arrow,
+        show_clear_icon,
+        style_sheet,
+    } = field;
+
+    let secure_value = is_secure.then(|| value.secure());
+    let value = secure_value.as_ref().unwrap_or(value);
+
+    let (icon, icon_font) = match (spinner, icon) {
+        (Some(frame), _) => (frame, font.clone()),
+        (None, Some((icon, icon_font))) => (icon, icon_font.clone()),
+        (None, None) => (Renderer::ARROW_DOWN_ICON, Renderer::ICON_FONT),
+    };
+
+    let underline = underline.then(|| {
+        if is_focused {
+            (2.0, style_sheet.underline_focused_color())
+        } else {
+            (1.0, style_sheet.underline_active_color())
+        }
+    });
+
+    let size = text_size.unwrap_or(renderer.default_size());
+    let suffix_width = suffix.map_or(0.0, |suffix| {
+        renderer.measure_width(suffix, size, font.clone())
+    });
+
+    let clear_icon_width = if show_clear_icon {
+        CLEAR_ICON_WIDTH
+    } else {
+        0.0
+    };
+
+    let trailing_width = arrow_width + clear_icon_width + suffix_width;
+
+    // The prefix is laid out before the text bounds, while the suffix and
+    // the icons follow them
+    let (prefix_x, suffix_x) = if right_to_left {
+        (
+            text_bounds.x + text_bounds.width,
+            bounds.x + f32::from(padding.left) + arrow_width + clear_icon_width,
+        )
+    } else {
+        (
+            bounds.x + f32::from(padding.left),
+            bounds.x + bounds.width - f32::from(padding.right) - trailing_width,
+        )
+    };
+
+    let prefix_bounds = Rectangle {
+        x: prefix_x,
+        y: bounds.center_y(),
+        width: bounds.width
+            - f32::from(padding.horizontal())
+            - text_bounds.width
+            - if right_to_left { trailing_width } else { 0.0 },
+        height: f32::from(size),
+    };
+
+    let suffix_bounds = Rectangle {
+        x: suffix_x,
+        y: bounds.center_y(),
+        width: suffix_width,
+        height: f32::from(size),
+    };
+
+    let (arrow_x, arrow_alignment) = if right_to_left {
+        (
+            bounds.x + f32::from(padding.left),
+            alignment::Horizontal::Left,
+        )
+    } else {
+        (
+            bounds.x + bounds.width - f32::from(padding.right),
+            alignment::Horizontal::Right,
+        )
+    };
+
+    if pick_list_is_open {
+        if !right_to_left {
+            text_bounds.width -= trailing_width;
+        }
+
+        let is_mouse_over_text = bounds.contains(cursor_position);
+
+        let style = if is_focused {
+            style_sheet.text_input_focused()
+        } else if is_mouse_over_text {
+            style_sheet.text_input_hovered()
+        } else {
+            style_sheet.text_input_active()
+        };
+
+        if show_arrow {
+            renderer.fill_text(Text {
+                content: &icon.to_string(),
+                font: icon_font.clone(),
+                size: bounds.height * style_sheet.icon_size(),
+                bounds: Rectangle {
+                    x: arrow_x,
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                color: style_sheet.text_input_value_color(),
+                horizontal_alignment: arrow_alignment,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+
+        if show_clear_icon {
+            fill_clear_icon(
+                renderer,
+                clear_icon_bounds(bounds, padding, arrow_width, right_to_left),
+                font,
+                bounds.height * style_sheet.icon_size(),
+                style_sheet.text_input_value_color(),
+            );
+        }
+
+        for (quad, background) in field_quads(
+            bounds,
+            style.background,
+            style.border_radius,
+            style.border_width,
+            style.border_color,
+            underline,
+        ) {
+            renderer.fill_quad(quad, background);
+        }
+
+        fill_affixes(
+            renderer,
+            prefix.map(|prefix| (prefix, prefix_bounds)),
+            suffix.map(|suffix| (suffix, suffix_bounds)),
+            font,
+            size,
+            style_sheet.text_input_placeholder_color(),
+        );
+
+        let text = value.to_string();
+
+        let mut measurements =
+            Measurements::new(renderer, font.clone(), size, value);
+
+        let cursor = if is_focused {
+            match cursor.state(value) {
+                cursor::State::Index(_) if !is_caret_visible => None,
+                cursor::State::Index(position) => {
+                    let text_value_width = measurements.width(position);
+
+                    Some((
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: text_bounds.x + text_value_width,
+                                y: text_bounds.y,
+                                width: 1.0,
+                                height: text_bounds.height,
+                            },
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        style_sheet.text_input_value_color(),
+                    ))
+                }
+                cursor::State::Selection { start, end } => {
+                    let left = start.min(end);
+                    let right = end.max(start);
+
+                    let left_position = measurements.width(left);
+                    let right_position = measurements.width(right);
+
+                    Some((
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: text_bounds.x + left_position,
+                                y: text_bounds.y,
+                                width: right_position - left_position,
+                                height: text_bounds.height,
+                            },
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        selection_color(style_sheet, selection_opacity),
+                    ))
+                }
+            }
+        } else {
+            None
+        };
+
+        let text_width = if text.is_empty() {
+            renderer.measure_width(placeholder, size, font.clone())
+        } else {
+            measurements.width(value.len())
+        };
+
+        let render = |renderer: &mut Renderer| {
+            if let Some((cursor, color)) = cursor {
+                renderer.fill_quad(cursor, color);
+            }
+
+            renderer.fill_text(Text {
+                content: if text.is_empty() { placeholder } else { &text },
+                color: if text.is_empty() {
+                    style_sheet.text_input_placeholder_color()
+                } else {
+                    style_sheet.text_input_value_color()
+                },
+                font: font.clone(),
+                bounds: editing_text_bounds(bounds, text_bounds),
+                size: f32::from(size),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        };
+
+        if text_width > text_bounds.width {
+            renderer.with_layer(text_bounds, |renderer| {
+                renderer
+                    .with_translation(Vector::new(-scroll_offset, 0.0), render)
+            });
+        } else {
+            let offset =
+                alignment_offset(text_alignment, text_width, text_bounds.width);
+
+            renderer.with_translation(Vector::new(offset, 0.0), render);
+        }
+    } else {
+        let is_mouse_over = bounds.contains(cursor_position);
+        let is_selected = selected.is_some();
+
+        let style = if !is_enabled {
+            style_sheet.disabled()
+        } else if is_mouse_over {
+            style_sheet.pick_list_hovered()
+        } else {
+            style_sheet.pick_list_active()
+        };
+
+        let icon_color = if is_enabled {
+            style_sheet.text_input_value_color()
+        } else {
+            style.text_color
+        };
+
+        let text_color = if !is_enabled {
+            style.text_color
+        } else if is_mouse_over {
+            style_sheet.pick_list_hovered_text_color()
+        } else {
+            style_sheet.pick_list_active_text_color()
+        };
+
+        for (quad, background) in field_quads(
+            bounds,
+            style.background,
+            style.border_radius,
+            style.border_width,
+            style.border_color,
+            underline,
+        ) {
+            renderer.fill_quad(quad, background);
+        }
+
+        if show_arrow {
+            renderer.fill_text(Text {
+                content: &icon.to_string(),
+                font: icon_font.clone(),
+                size: bounds.height * style.icon_size,
+                bounds: Rectangle {
+                    x: arrow_x,
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                color: icon_color,
+                horizontal_alignment: arrow_alignment,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+
+        if show_clear_icon {
+            fill_clear_icon(
+                renderer,
+                clear_icon_bounds(bounds, padding, arrow_width, right_to_left),
+                font,
+                bounds.height * style.icon_size,
+                icon_color,
+            );
+        }
+
+        fill_affixes(
+            renderer,
+            prefix.map(|prefix| (prefix, prefix_bounds)),
+            suffix.map(|suffix| (suffix, suffix_bounds)),
+            font,
+            size,
+            style.placeholder_color,
+        );
+
+        let label = selected.map(ToString::to_string);
+    
+        if let Some(label) =
+            label.as_ref().map(String::as_str).or_else(|| Some(placeholder))
+        {
+            let area = if right_to_left {
+                Rectangle {
+                    x: bounds.x + trailing_width,
+                    width: bounds.width - trailing_width - prefix_bounds.width,
+                    ..bounds
+                }
+            } else {
+                Rectangle {
+                    x: bounds.x + prefix_bounds.width,
+                    width: bounds.width - prefix_bounds.width,
+                    ..bounds
+                }
+            };
+
+            let mut label_bounds = collapsed_label_bounds(
+                area,
+                padding,
+                f32::from(size),
+                collapsed_alignment,
+            );
+
+            let max_width = if right_to_left {
+                label_bounds.width
+            } else {
+                label_bounds.width - trailing_width
+            };
+
+            let label = if wrap {
+                label_bounds.width = max_width;
+                label_bounds.height =
+                    bounds.height - f32::from(padding.vertical());
+
+                Cow::Borrowed(label)
+            } else {
+                ellipsize(renderer, label, size, font, max_width)
+            };
+    
+            renderer.fill_text(Text {
+                content: &label,
+                size: f32::from(size),
+                font: font.clone(),
+                color: if is_selected {
+                    selected_color.filter(|_| is_enabled).unwrap_or(text_color)
+                } else if is_placeholder_disabled {
+                    style.text_color
+                } else {
+                    style.placeholder_color
+                },
+                bounds: label_bounds,
+                horizontal_alignment: collapsed_alignment,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
+
+/// Returns the clickable bounds of the arrow icon of a
+/// [`SearchablePickList`].
+///
+/// It spans the space reserved for the arrow and the right padding, or the
+/// left padding when laid out from right to left.
+fn arrow_bounds(
+    bounds: Rectangle,
+    padding: Padding,
+    arrow_width: f32,
+    right_to_left: bool,
+) -> Rectangle {
+    if right_to_left {
+        return Rectangle {
+            width: f32::from(padding.left) + arrow_width,
+            ..bounds
+        };
+    }
+
+    let width = f32::from(padding.right) + arrow_width;
+
+    Rectangle {
+        x: bounds.x + bounds.width - width,
+        width,
+        ..bounds
+    }
+}
+
+/// Returns the clickable bounds of the clear icon of a
+/// [`SearchablePickList`], next to its arrow icon.
+fn clear_icon_bounds(
+    bounds: Rectangle,
+    padding: Padding,
+    arrow_width: f32,
+    right_to_left: bool,
+) -> Rectangle {
+    let arrow = arrow_bounds(bounds, padding, arrow_width, right_to_left);
+
+    Rectangle {
+        x: if right_to_left {
+            arrow.x + arrow.width
+        } else {
+            arrow.x - CLEAR_ICON_WIDTH
+        },
+        width: CLEAR_ICON_WIDTH,
+        ..bounds
+    }
+}
+
+/// Draws the prefix and the suffix of a [`SearchablePickList`] in their
+/// bounds.
+fn fill_affixes<Renderer>(
+    renderer: &mut Renderer,
+    prefix: Option<(&str, Rectangle)>,
+    suffix: Option<(&str, Rectangle)>,
+    font: &Renderer::Font,
+    size: u16,
+    color: Color,
+) where
+    Renderer: text::Renderer,
+{
+    for (content, bounds) in prefix.into_iter().chain(suffix) {
+        renderer.fill_text(Text {
+            content,
+            size: f32::from(size),
+            font: font.clone(),
+            color,
+            bounds,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+}
+
+/// Draws the clear icon of a [`SearchablePickList`] in the given bounds.
+///
+/// The icon font has no glyph for it, so it is drawn with the text font.
+fn fill_clear_icon<Renderer>(
+    renderer: &mut Renderer,
+    icon_bounds: Rectangle,
+    font: &Renderer::Font,
+    size: f32,
+    color: Color,
+) where
+    Renderer: text::Renderer,
+{
+    renderer.fill_text(Text {
+        content: "x",
+        font: font.clone(),
+        size,
+        bounds: Rectangle {
+            x: icon_bounds.center_x(),
+            y: icon_bounds.center_y(),
+            ..icon_bounds
+        },
+        color,
+        horizontal_alignment: alignment::Horizontal::Center,
+        vertical_alignment: alignment::Vertical::Center,
+    });
+}
+
+/// Returns the bounds of the field of a [`SearchablePickList`], excluding
+/// the helper or error text below it.
+fn field_bounds(layout: Layout<'_>, padding: Padding) -> Rectangle {
+    let text_bounds = layout.children().next().unwrap().bounds();
+
+    Rectangle {
+        height: text_bounds.height + f32::from(padding.vertical()),
+        ..layout.bounds()
+    }
+}
+
+/// Returns the text displayed below a [`SearchablePickList`] and its color.
+///
+/// The error text takes precedence over the helper text.
+fn validation_message<'b>(
+    helper_text: Option<&'b str>,
+    error_text: Option<&'b str>,
+    style_sheet: &dyn StyleSheet,
+) -> Option<(&'b str, Color)> {
+    match (error_text, helper_text) {
+        (Some(error), _) => Some((error, style_sheet.error_text_color())),
+        (None, Some(helper)) => Some((helper, style_sheet.helper_text_color())),
+        (None, None) => None,
+    }
+}
+
+/// Returns the indices of the options matching the given text of a
+/// [`SearchablePickList`], in their original order.
+fn filter<T: ToString>(options: &[T], query: &str) -> Vec<usize> {
+    options
+        .iter()
+        .enumerate()
+        .filter(|(_, option)| matches_query(*option, query))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Keeps the first of the given indices pointing to options with the same
+/// label, in their original order.
+fn dedup<T: ToString>(options: &[T], mut indices: Vec<usize>) -> Vec<usize> {
+    let mut labels = HashSet::new();
+
+    indices.retain(|&i| labels.insert(options[i].to_string()));
+    indices
+}
+
+/// Returns whether the given option matches the text of a
+/// [`SearchablePickList`], ignoring case.
+fn matches_query<T: ToString>(option: &T, query: &str) -> bool {
+    option
+        .to_string()
+        .to_lowercase()
+        .contains(&query.to_lowercase())
+}
+
+/// Returns the byte range of the first case-insensitive occurrence of the
+/// query in the given label.
+fn match_range(label: &str, query: &str) -> Option<Range<usize>> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+
+    if query.is_empty() {
+        return None;
+    }
+
+    label.char_indices().find_map(|(start, _)| {
+        let mut lowercase = label[start..].char_indices().flat_map(|(i, c)| {
+            c.to_lowercase()
+                .map(move |lower| (start + i + c.len_utf8(), lower))
+        });
+
+        let mut end = start;
+
+        for expected in &query {
+            match lowercase.next() {
+                Some((next_end, lower)) if lower == *expected => {
+                    end = next_end;
+                }
+                _ => return None,
+            }
+        }
+
+        Some(start..end)
+    })
+}
+
+/// Returns the quads of the box of a [`SearchablePickList`].
+///
+/// When an underline width and color are provided, the box is drawn without
+/// a border and a line is drawn at its bottom edge instead.
+fn field_quads(
+    bounds: Rectangle,
+    background: Background,
+    border_radius: f32,
+    border_width: f32,
+    border_color: Color,
+    underline: Option<(f32, Color)>,
+) -> Vec<(renderer::Quad, Background)> {
+    match underline {
+        None => vec![(
+            renderer::Quad {
+                bounds,
+                border_radius,
+                border_width,
+                border_color,
+            },
+            background,
+        )],
+        Some((width, color)) => vec![
+            (
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            ),
+            (
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - width,
+                        height: width,
+                        ..bounds
+                    },
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Background::Color(color),
+            ),
+        ],
+    }
+}
+
+/// Returns the bounds of the label of a closed [`SearchablePickList`] with
+/// the given horizontal alignment, vertically centered in the field.
+fn collapsed_label_bounds(
+    bounds: Rectangle,
+    padding: Padding,
+    text_size: f32,
+    alignment: alignment::Horizontal,
+) -> Rectangle {
+    let width = bounds.width - f32::from(padding.horizontal());
+
+    let x = match alignment {
+        alignment::Horizontal::Left => bounds.x + f32::from(padding.left),
+        alignment::Horizontal::Center => {
+            bounds.x + f32::from(padding.left) + width / 2.0
+        }
+        alignment::Horizontal::Right => {
+            bounds.x + f32::from(padding.left) + width
+        }
+    };
+
+    Rectangle {
+        x,
+        y: bounds.center_y(),
+        width,
+        height: text_size,
+    }
+}
+
+/// Truncates the label of a closed [`SearchablePickList`] with an ellipsis
+/// when it does not fit in the given width.
+fn ellipsize<'a, Renderer>(
+    renderer: &Renderer,
+    label: &'a str,
+    size: u16,
+    font: &Renderer::Font,
+    max_width: f32,
+) -> Cow<'a, str>
+where
+    Renderer: text::Renderer,
+{
+    if renderer.measure_width(label, size, font.clone()) <= max_width {
+        return Cow::Borrowed(label);
+    }
+
+    let value = Value::new(label);
+    let truncated =
+        |end| format!("{}…", value.until(end).to_string().trim_end());
+
+    // The whole label does not fit, so look for the longest prefix that
+    // still fits with the ellipsis appended
+    let (mut fits, mut overflows) = (0, value.len());
+
+    while overflows - fits > 1 {
+        let middle = (fits + overflows) / 2;
+
+        if renderer.measure_width(&truncated(middle), size, font.clone())
+            <= max_width
+        {
+            fits = middle;
+        } else {
+            overflows = middle;
+        }
+    }
+
+    Cow::Owned(truncated(fits))
+}
+
+/// Turns pasted text into a single line [`Value`].
+///
+/// Line breaks and tabs are replaced by a space, so the words they separate
+/// stay apart, while any other control character is dropped.
+fn flatten(text: &str) -> Value {
+    let flattened: String = text
+        .replace("\r\n", " ")
+        .chars()
+        .filter_map(|c| match c {
+            '\n' | '\r' | '\t' => Some(' '),
+            c if c.is_control() => None,
+            c => Some(c),
+        })
+        .collect();
+
+    Value::new(&flattened)
+}
+
+/// Returns how far text of the given width is shifted right to be aligned
+/// in the available width. Text that does not fit is not shifted.
+fn alignment_offset(
+    alignment: alignment::Horizontal,
+    text_width: f32,
+    available_width: f32,
+) -> f32 {
+    let space = (available_width - text_width).max(0.0);
+
+    match alignment {
+        alignment::Horizontal::Left => 0.0,
+        alignment::Horizontal::Center => space / 2.0,
+        alignment::Horizontal::Right => space,
+    }
+}
+
+/// Returns the bounds of the text of an open [`SearchablePickList`],
+/// vertically centered in the field regardless of its height.
+fn editing_text_bounds(bounds: Rectangle, text_bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        y: bounds.center_y(),
+        width: f32::INFINITY,
+        ..text_bounds
+    }
+}
+
+/// Returns the frame of the spinner at the given instant for a
+/// [`SearchablePickList`] that started loading at `since`.
+fn spinner_frame(since: Option<Instant>, now: Instant) -> char {
+    let elapsed = since.map(|since| now - since).unwrap_or_default();
+    let frame = elapsed.as_millis() / SPINNER_INTERVAL.as_millis();
+
+    SPINNER_FRAMES[frame as usize % SPINNER_FRAMES.len()]
+}
+
+/// Returns whether the blinking caret of a [`SearchablePickList`] that was
+/// last moved at `since` is displayed at the given instant.
+fn is_caret_visible(since: Option<Instant>, now: Instant) -> bool {
+    let elapsed = since.map(|since| now - since).unwrap_or_default();
+    let phase = elapsed.as_millis() / CARET_BLINK_INTERVAL.as_millis();
+
+    phase % 2 == 0
+}
+
+/// Returns the selection bounds of the cursor and the length of the value,
+/// which change whenever the text is edited or the caret is moved.
+fn caret_position(cursor: Cursor, value: &Value) -> (usize, usize, usize) {
+    (cursor.start(value), cursor.end(value), value.len())
+}
+
+/// Returns the color of the selection of a [`SearchablePickList`] with the
+/// given opacity applied.
+fn selection_color(style_sheet: &dyn StyleSheet, opacity: f32) -> Color {
+    let color = style_sheet.text_input_selection_color();
+
+    Color {
+        a: color.a * opacity,
+        ..color
+    }
+}
+
+/// Returns the horizontal scroll offset of the text of a
+/// [`SearchablePickList`] that keeps the cursor visible, moving the previous
+/// offset only when the cursor goes past the visible bounds.
+fn next_scroll_offset(
+    previous: f32,
+    cursor_x: f32,
+    text_width: f32,
+    visible_width: f32,
+) -> f32 {
+    let max_offset = (text_width + 5.0 - visible_width).max(0.0);
+
+    let offset = if cursor_x + 5.0 > previous + visible_width {
+        cursor_x + 5.0 - visible_width
+    } else if cursor_x < previous {
+        cursor_x
+    } else {
+        previous
+    };
+
+    offset.max(0.0).min(max_offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::renderer::Null;
+
+    const OPTIONS: [&str; 3] = ["Apple", "Banana", "Cherry"];
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Changed(String),
+        Selected(&'static str),
+        Cleared,
+        Hovered(&'static str),
+        Opened,
+        Closed,
+        Custom,
+        Toggled(&'static str, bool),
+        Selections(Vec<&'static str>),
+        Filtered(usize),
+    }
+
+    fn pick_list<'a, Renderer: text::Renderer>(
+        state: &'a mut State<&'static str>,
+        selected: Option<&'static str>,
+    ) -> SearchablePickList<'a, &'static str, Message, Renderer> {
+        SearchablePickList::new(
+            state,
+            "Search...",
+            "",
+            selected,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+    }
+
+    fn update(
+        pick_list: &mut SearchablePickList<'_, &'static str, Message, Null>,
+        events: impl IntoIterator<Item = Event>,
+        cursor_position: Point,
+    ) -> Vec<Message> {
+        update_with(
+            pick_list,
+            &Null,
+            &mut clipboard::Null,
+            events,
+            cursor_position,
+        )
+    }
+
+    fn update_with<'a, Renderer: text::Renderer + 'a>(
+        pick_list: &mut SearchablePickList<'a, &'static str, Message, Renderer>,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        events: impl IntoIterator<Item = Event>,
+        cursor_position: Point,
+    ) -> Vec<Message> {
+        let node = Widget::<Message, Renderer>::layout(
+            pick_list,
+            renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        for event in events {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = pick_list.on_event(
+                event,
+                Layout::new(&node),
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut shell,
+            );
+        }
+
+        messages
+    }
+
+    fn key_press(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    fn click() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    #[test]
+    fn prehovers_selected_option_on_open() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, Some("Banana"));
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        assert!(pick_list.state().pick_list.is_open);
+        assert_eq!(pick_list.state().pick_list.hovered_option, Some(1));
+    }
+
+    #[test]
+    fn does_not_prehover_when_disabled() {
+        let mut state = State::new();
+        let mut pick_list =
+            pick_list(&mut state, Some("Banana")).prehover_selected(false);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        assert!(pick_list.state().pick_list.is_open);
+        assert_eq!(pick_list.state().pick_list.hovered_option, None);
+    }
+
+    #[test]
+    fn on_key_intercepts_key_presses() {
+        let mut state = State::focused();
+        let mut pick_list =
+            pick_list(&mut state, None).on_key(|key_code, _| {
+                (key_code == keyboard::KeyCode::F2).then(|| Message::Custom)
+            });
+
+        let messages = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::F2),
+                key_press(keyboard::KeyCode::Backspace),
+            ],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(
+            messages,
+            [Message::Custom, Message::Changed(String::new())]
+        );
+    }
+
+    #[test]
+    fn selection_opacity_is_applied_to_the_selection() {
+        let mut renderer = Monospace::default();
+        let mut state = State::focused();
+        state.pick_list.is_open = true;
+        state.cursor.select_range(1, 3);
+
+        let pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Banana",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .selection_opacity(0.25);
+
+        let node = Widget::<Message, Monospace>::layout(
+            &pick_list,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        pick_list.draw(&mut renderer, Layout::new(&node), Point::ORIGIN, None);
+
+        let color = pick_list.style_sheet.text_input_selection_color();
+        let selection = renderer
+            .quads
+            .iter()
+            .zip(&renderer.backgrounds)
+            .find(|(bounds, _)| bounds.width == 20.0)
+            .map(|(_, background)| *background);
+
+        assert_eq!(
+            selection,
+            Some(Background::Color(Color {
+                a: color.a * 0.25,
+                ..color
+            }))
+        );
+        assert_eq!(pick_list.selection_opacity(3.0).selection_opacity, 1.0);
+    }
+
+    #[test]
+    fn snapshot_restores_cursor_position() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None);
+
+        let messages = update(
+            &mut pick_list,
+            "abc"
+                .chars()
+                .map(|c| Event::Keyboard(keyboard::Event::CharacterReceived(c)))
+                .chain([key_press(keyboard::KeyCode::Left)]),
+            Point::ORIGIN,
+        );
+
+        let snapshot = pick_list.state().snapshot(None);
+        assert_eq!(messages.last(), Some(&Message::Changed("abc".into())));
+        assert_eq!(snapshot.value, "abc");
+        assert_eq!(snapshot.selection_start, 2);
+
+        let mut restored = State::<&str>::new();
+        restored.restore(&snapshot);
+
+        assert_eq!(restored.snapshot(None), snapshot);
+    }
+
+    #[test]
+    fn open_on_hover_previews_menu_without_focus() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None).open_on_hover(true);
+
+        let hover =
+            |position| Event::Mouse(mouse::Event::CursorMoved { position });
+
+        let inside = Point::new(10.0, 10.0);
+        let _ = update(&mut pick_list, [hover(inside)], inside);
+
+        assert!(pick_list.state().pick_list.is_open);
+        assert!(!pick_list.state().is_focused());
+
+        let outside = Point::new(10.0, 90.0);
+        let _ = update(&mut pick_list, [hover(outside)], outside);
+
+        assert!(!pick_list.state().pick_list.is_open);
+    }
+
+    #[test]
+    fn spinner_cycles_through_frames() {
+        let since = Instant::now();
+
+        assert_eq!(spinner_frame(None, since), SPINNER_FRAMES[0]);
+        assert_eq!(
+            spinner_frame(Some(since), since + SPINNER_INTERVAL * 2),
+            SPINNER_FRAMES[2]
+        );
+        assert_eq!(
+            spinner_frame(Some(since), since + SPINNER_INTERVAL * 5),
+            SPINNER_FRAMES[1]
+        );
+    }
+
+    #[test]
+    fn clicks_in_ignored_regions_keep_menu_open() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None)
+            .ignore_outside_clicks_in(vec![Rectangle {
+                x: 150.0,
+                y: 50.0,
+                width: 50.0,
+                height: 50.0,
+            }]);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+        let _ = update(&mut pick_list, [click()], Point::new(160.0, 60.0));
+
+        assert!(pick_list.state().pick_list.is_open);
+        assert!(pick_list.state().is_focused());
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 60.0));
+
+        assert!(!pick_list.state().pick_list.is_open);
+    }
+
+    #[test]
+    fn collapsed_label_is_aligned_independently_of_editing_text() {
+        let mut state = State::new();
+        let pick_list = pick_list(&mut state, Some("Apple"))
+            .padding(10)
+            .collapsed_alignment(alignment::Horizontal::Center);
+
+        let node = Widget::<Message, Null>::layout(
+            &pick_list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+        let layout = Layout::new(&node);
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let label = collapsed_label_bounds(
+            bounds,
+            pick_list.padding,
+            20.0,
+            pick_list.collapsed_alignment,
+        );
+
+        assert_eq!(label.x, bounds.center_x());
+        assert_eq!(text_bounds.x, bounds.x + 10.0);
+    }
+
+    #[test]
+    fn delete_clears_selection_when_not_editable() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, Some("Banana"))
+            .editable(false)
+            .on_clear(Message::Cleared);
+
+        let messages = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Delete)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(messages, vec![Message::Cleared]);
+        assert_eq!(pick_list.selected, None);
+    }
+
+    #[test]
+    fn delete_edits_text_when_editable() {
+        let mut state = State::focused();
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Ban",
+            Some("Banana"),
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .on_clear(Message::Cleared);
+
+        let messages = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Delete)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(messages, vec![Message::Changed(String::from("an"))]);
+        assert_eq!(pick_list.selected, Some("Banana"));
+    }
+
+    #[test]
+    fn small_movements_do_not_start_a_drag() {
+        let mut state = State::focused();
+        state.pick_list.is_open = true;
+
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Banana",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        );
+
+        let _ = update(&mut pick_list, [click()], Point::new(20.0, 10.0));
+        let _ = update(
+            &mut pick_list,
+            [Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(22.0, 10.0),
+            })],
+            Point::new(22.0, 10.0),
+        );
+
+        assert!(!pick_list.state().is_dragging);
+        assert_eq!(
+            pick_list.state().cursor().selection(&pick_list.value),
+            None
+        );
+
+        let _ = update(
+            &mut pick_list,
+            [Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(40.0, 10.0),
+            })],
+            Point::new(40.0, 10.0),
+        );
+
+        assert!(pick_list.state().is_dragging);
+    }
+
+    #[test]
+    fn underline_only_draws_the_bottom_edge() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 200.0,
+            height: 30.0,
+        };
+        let background = Background::Color(Color::WHITE);
+
+        let quads = field_quads(
+            bounds,
+            background,
+            5.0,
+            1.0,
+            Color::BLACK,
+            Some((2.0, Color::BLACK)),
+        );
+
+        let borders: Vec<_> = quads
+            .iter()
+            .filter(|(quad, _)| quad.border_width > 0.0)
+            .collect();
+
+        let lines: Vec<_> = quads
+            .iter()
+            .filter(|(_, quad_background)| *quad_background != background)
+            .map(|(quad, _)| quad.bounds)
+            .collect();
+
+        assert!(borders.is_empty());
+        assert_eq!(
+            lines,
+            vec![Rectangle {
+                y: 28.0,
+                height: 2.0,
+                ..bounds
+            }]
+        );
+    }
+
+    #[test]
+    fn hover_shorter_than_delay_does_not_open_menu() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None)
+            .open_on_hover(true)
+            .hover_open_delay(Duration::from_secs(60));
+
+        let hover =
+            |position| Event::Mouse(mouse::Event::CursorMoved { position });
+
+        let inside = Point::new(10.0, 10.0);
+        let _ = update(&mut pick_list, [hover(inside), hover(inside)], inside);
+
+        assert!(!pick_list.state().pick_list.is_open);
+
+        let outside = Point::new(10.0, 90.0);
+        let _ = update(&mut pick_list, [hover(outside)], outside);
+
+        assert_eq!(pick_list.state().hover_open_at, None);
+    }
+
+    #[test]
+    fn resting_pointer_opens_menu_after_delay() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None)
+            .open_on_hover(true)
+            .hover_open_delay(Duration::from_secs(60));
+
+        let inside = Point::new(10.0, 10.0);
+        let _ = update(
+            &mut pick_list,
+            [Event::Mouse(mouse::Event::CursorMoved { position: inside })],
+            inside,
+        );
+
+        let rested = Instant::now();
+
+        assert!(pick_list.state().needs_tick());
+        assert_eq!(pick_list.state.poll(rested), vec![]);
+        assert!(!pick_list.state().is_open());
+
+        let due = rested + Duration::from_secs(60);
+
+        assert_eq!(pick_list.state.poll(due), vec![Deferred::Opened]);
+        assert!(pick_list.state().is_open());
+        assert!(!pick_list.state().needs_tick());
+    }
+
+    #[test]
+    fn hovered_index_follows_the_menu() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, Some("Cherry"));
+
+        assert_eq!(pick_list.state().hovered_index(), None);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        assert_eq!(pick_list.state().hovered_index(), Some(2));
+    }
+
+    #[test]
+    fn text_is_vertically_centered_in_tall_fields() {
+        let mut state = State::new();
+        let pick_list = pick_list(&mut state, None).padding(Padding {
+            top: 40,
+            right: 5,
+            bottom: 10,
+            left: 5,
+        });
+
+        let node = Widget::<Message, Null>::layout(
+            &pick_list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+        let layout = Layout::new(&node);
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let label = collapsed_label_bounds(
+            bounds,
+            pick_list.padding,
+            20.0,
+            alignment::Horizontal::Left,
+        );
+
+        assert_eq!(bounds.height, 70.0);
+        assert_eq!(label.y, bounds.center_y());
+        assert_eq!(
+            editing_text_bounds(bounds, text_bounds).y,
+            bounds.center_y()
+        );
+    }
+
+    #[test]
+    fn auto_selects_the_single_remaining_match() {
+        let mut state = State::focused();
+        let mut pick_list =
+            pick_list(&mut state, None).auto_select_single_match(true);
+
+        let messages = update(
+            &mut pick_list,
+            "an".chars().map(|c| {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            }),
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Changed(String::from("a")),
+                Message::Changed(String::from("an")),
+                Message::Changed(String::from("Banana")),
+                Message::Selected("Banana"),
+            ]
+        );
+        assert!(!pick_list.state().is_focused());
+    }
+
+    #[test]
+    fn dragging_the_menu_scrollbar_keeps_menu_open() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        // The cursor is unavailable while it is over the menu overlay
+        let _ = update(&mut pick_list, [click()], Point::new(-1.0, -1.0));
+
+        let outside = Point::new(10.0, 90.0);
+        let _ = update(
+            &mut pick_list,
+            [
+                Event::Mouse(mouse::Event::CursorMoved { position: outside }),
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            ],
+            outside,
+        );
+
+        assert!(pick_list.state().pick_list.is_open);
+        assert!(pick_list.state().is_focused());
+    }
+
+    #[test]
+    fn validation_text_grows_the_layout() {
+        let mut state = State::new();
+        let pick_list = pick_list(&mut state, None)
+            .helper_text(Some(String::from("Pick a fruit")));
+
+        let node = Widget::<Message, Null>::layout(
+            &pick_list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+        let layout = Layout::new(&node);
+
+        assert_eq!(field_bounds(layout, pick_list.padding).height, 20.0);
+        assert_eq!(layout.bounds().height, 20.0 + MESSAGE_SPACING + 15.0);
+    }
+
+    #[test]
+    fn error_text_wins_over_helper_text() {
+        let style_sheet: Box<dyn StyleSheet> = Default::default();
+
+        assert_eq!(
+            validation_message(
+                Some("Pick a fruit"),
+                Some("Required"),
+                style_sheet.as_ref()
+            ),
+            Some(("Required", style_sheet.error_text_color()))
+        );
+        assert_eq!(
+            validation_message(
+                Some("Pick a fruit"),
+                None,
+                style_sheet.as_ref()
+            ),
+            Some(("Pick a fruit", style_sheet.helper_text_color()))
+        );
+    }
+
+    #[test]
+    fn hover_messages_are_debounced() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None)
+            .on_hover_option(Message::Hovered)
+            .hover_debounce(Duration::from_millis(20));
+
+        let position = Point::new(-1.0, -1.0);
+        let hover = Event::Mouse(mouse::Event::CursorMoved { position });
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        let mut messages = Vec::new();
+
+        for index in 0..OPTIONS.len() {
+            pick_list.state.pick_list.hovered_option = Some(index);
+
+            messages.extend(update(&mut pick_list, [hover.clone()], position));
+        }
+
+        let settled = Instant::now();
+
+        assert!(messages.is_empty());
+        assert_eq!(pick_list.state.poll(settled), vec![]);
+
+        let due = settled + Duration::from_millis(20);
+
+        assert_eq!(
+            pick_list.state.poll(due),
+            vec![Deferred::Hovered("Cherry")]
+        );
+        assert_eq!(pick_list.state.poll(due), vec![]);
+
+        messages.extend(update(&mut pick_list, [hover], position));
+
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn typing_filters_the_options() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+        let _ = update(
+            &mut pick_list,
+            "AN".chars().map(|c| {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            }),
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(pick_list.visible_options(), &["Banana"]);
+        assert_eq!(pick_list.state().filtered_indices, Some(vec![1]));
+
+        let _ = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::Backspace),
+                key_press(keyboard::KeyCode::Backspace),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(pick_list.visible_options(), &OPTIONS);
+    }
+
+    #[test]
+    fn edits_can_be_undone_and_redone() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None);
+
+        let command = Event::Keyboard(keyboard::Event::ModifiersChanged(
+            keyboard::Modifiers::COMMAND,
+        ));
+
+        let mut messages = update(
+            &mut pick_list,
+            "ab".chars()
+                .map(|c| Event::Keyboard(keyboard::Event::CharacterReceived(c)))
+                .chain([
+                    key_press(keyboard::KeyCode::Backspace),
+                    command,
+                    key_press(keyboard::KeyCode::Z),
+                    key_press(keyboard::KeyCode::Z),
+                    key_press(keyboard::KeyCode::Y),
+                ]),
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(
+            messages.split_off(3),
+            vec![
+                Message::Changed(String::from("ab")),
+                Message::Changed(String::new()),
+                Message::Changed(String::from("ab")),
+            ]
+        );
+        assert_eq!(pick_list.value.to_string(), "ab");
+    }
+
+    #[test]
+    fn arrow_keys_navigate_the_open_menu() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+        let _ = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Down),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(pick_list.state().hovered_index(), Some(1));
+
+        let _ = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::Up),
+                key_press(keyboard::KeyCode::Up),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(pick_list.state().hovered_index(), Some(2));
+    }
+
+    #[test]
+    fn enter_selects_the_hovered_option() {
+        let mut state = State::new();
+        let mut pick_list =
+            pick_list(&mut state, None).on_submit(Message::Custom);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        let messages = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Enter),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Changed(String::from("Apple")),
+                Message::Selected("Apple"),
+            ]
+        );
+        assert_eq!(pick_list.selected, Some("Apple"));
+        assert_eq!(pick_list.value.to_string(), "Apple");
+        assert!(!pick_list.state().pick_list.is_open);
+        assert!(!pick_list.state().is_focused());
+    }
+
+    #[test]
+    fn selecting_keeps_the_text_when_not_synced() {
+        let mut state = State::new();
+        let mut pick_list =
+            pick_list(&mut state, None).sync_text_on_select(false);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        let messages = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Enter),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(messages, vec![Message::Selected("Apple")]);
+        assert_eq!(pick_list.value.to_string(), "");
+    }
+
+    #[test]
+    fn on_open_and_on_close_follow_the_menu() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None)
+            .on_open(Message::Opened)
+            .on_close(Message::Closed);
+
+        let opened = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+        let closed = update(&mut pick_list, [click()], Point::new(10.0, 90.0));
+
+        assert_eq!(opened, vec![Message::Opened]);
+        assert_eq!(closed, vec![Message::Closed]);
+    }
+
+    #[test]
+    fn scroll_offset_only_moves_past_the_visible_bounds() {
+        // Values that fit are never scrolled
+        assert_eq!(next_scroll_offset(0.0, 50.0, 80.0, 100.0), 0.0);
+
+        // Moving the cursor past the right edge scrolls just enough
+        assert_eq!(next_scroll_offset(0.0, 150.0, 300.0, 100.0), 55.0);
+
+        // Moving the cursor within the visible bounds keeps the offset
+        assert_eq!(next_scroll_offset(55.0, 100.0, 300.0, 100.0), 55.0);
+
+        // Moving the cursor past the left edge scrolls back to it
+        assert_eq!(next_scroll_offset(55.0, 20.0, 300.0, 100.0), 20.0);
+    }
+
+    #[test]
+    fn selects_all_on_first_click_after_gaining_focus() {
+        let mut state = State::new();
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Banana",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .select_all_first_click(true);
+
+        let inside = Point::new(10.0, 10.0);
+
+        let _ = update(&mut pick_list, [click()], inside);
+
+        assert!(pick_list.state().is_focused());
+        assert!(pick_list.state().first_click);
+
+        let _ = update(&mut pick_list, [click()], inside);
+
+        assert!(matches!(
+            pick_list.state().cursor.state(&Value::new("Banana")),
+            cursor::State::Selection { start: 0, end: 6 }
+        ));
+        assert!(!pick_list.state().first_click);
+    }
+
+    #[test]
+    fn shift_home_selects_from_caret_to_start() {
+        let mut state = State::focused();
+        state.cursor.move_to(3);
+
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Banana",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        );
+
+        let _ = update(
+            &mut pick_list,
+            [
+                Event::Keyboard(keyboard::Event::ModifiersChanged(
+                    keyboard::Modifiers::SHIFT,
+                )),
+                key_press(keyboard::KeyCode::Home),
+            ],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(
+            pick_list.state().cursor.selection(&Value::new("Banana")),
+            Some((0, 3))
+        );
+        assert_eq!(pick_list.state().cursor.end(&Value::new("Banana")), 0);
+    }
+
+    #[test]
+    fn shift_end_extends_the_selection_to_the_end() {
+        let mut state = State::focused();
+        state.cursor.select_range(1, 3);
+
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Banana",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        );
+
+        let _ = update(
+            &mut pick_list,
+            [
+                Event::Keyboard(keyboard::Event::ModifiersChanged(
+                    keyboard::Modifiers::SHIFT,
+                )),
+                key_press(keyboard::KeyCode::End),
+            ],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(
+            pick_list.state().cursor.selection(&Value::new("Banana")),
+            Some((1, 6))
+        );
+    }
+
+    #[test]
+    fn shift_home_extends_the_selection_to_the_start() {
+        let mut state = State::focused();
+        state.cursor.select_range(3, 5);
+
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Banana",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        );
+
+        let _ = update(
+            &mut pick_list,
+            [
+                Event::Keyboard(keyboard::Event::ModifiersChanged(
+                    keyboard::Modifiers::SHIFT,
+                )),
+                key_press(keyboard::KeyCode::Home),
+            ],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(
+            pick_list.state().cursor.selection(&Value::new("Banana")),
+            Some((0, 3))
+        );
+        assert_eq!(pick_list.state().cursor.end(&Value::new("Banana")), 0);
+    }
+
+    #[test]
+    fn double_click_selects_all_of_a_password() {
+        let mut state = State::new();
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "red apple",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .password(true);
+
+        let _ = update(
+            &mut pick_list,
+            [click(), click(), click()],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(
+            pick_list.state().cursor.selection(&Value::new("red apple")),
+            Some((0, 9))
+        );
+    }
+
+    #[test]
+    fn enter_commits_custom_values() {
+        let mut state = State::new();
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Durian",
+            Some("Apple"),
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .allow_custom(true)
+        .on_custom(Message::Changed);
+
+        let messages = update(
+            &mut pick_list,
+            [click(), key_press(keyboard::KeyCode::Enter)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(messages, vec![Message::Changed(String::from("Durian"))]);
+        assert_eq!(pick_list.selected, None);
+        assert!(!pick_list.state().pick_list.is_open);
+    }
+
+    #[test]
+    fn custom_values_are_ignored_unless_allowed() {
+        let mut state = State::new();
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Durian",
+            Some("Apple"),
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .on_custom(Message::Changed);
+
+        let messages = update(
+            &mut pick_list,
+            [click(), key_press(keyboard::KeyCode::Enter)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert!(messages.is_empty());
+        assert_eq!(pick_list.selected, Some("Apple"));
+    }
+
+    #[test]
+    fn clicking_the_clear_icon_clears_the_selection() {
+        let mut state = State::new();
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Apple",
+            Some("Apple"),
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .on_clear(Message::Cleared);
+
+        let clear_icon = Point::new(200.0 - ARROW_WIDTH - 10.0, 10.0);
+
+        let messages = update(&mut pick_list, [click()], clear_icon);
+
+        assert_eq!(
+            messages,
+            vec![Message::Changed(String::new()), Message::Cleared]
+        );
+        assert_eq!(pick_list.selected, None);
+        assert_eq!(pick_list.value.to_string(), "");
+        assert!(!pick_list.state().pick_list.is_open);
+
+        let _ = update(&mut pick_list, [click()], clear_icon);
+
+        assert!(pick_list.state().pick_list.is_open);
+    }
+
+    #[test]
+    fn state_opens_and_closes_the_menu() {
+        let mut state = State::new();
+        state.open();
+
+        assert!(state.is_open());
+
+        let mut pick_list = pick_list(&mut state, Some("Banana"));
+
+        let messages = update(
+            &mut pick_list,
+            [Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::default(),
+            ))],
+            Point::ORIGIN,
+        );
+
+        assert!(messages.is_empty());
+        assert_eq!(pick_list.state().hovered_index(), Some(1));
+
+        pick_list.state.close();
+
+        assert!(!pick_list.state().is_open());
+    }
+
+    #[test]
+    fn debounce_coalesces_changes() {
+        let mut state = State::focused();
+        let mut pick_list =
+            pick_list(&mut state, None).debounce(Duration::from_millis(50));
+
+        let typing = "ab"
+            .chars()
+            .map(|c| Event::Keyboard(keyboard::Event::CharacterReceived(c)));
+
+        let messages = update(&mut pick_list, typing, Point::ORIGIN);
+        let typed = Instant::now();
+
+        assert!(messages.is_empty());
+        assert!(pick_list.state().needs_tick());
+        assert_eq!(pick_list.state.poll(typed), vec![]);
+
+        let due = typed + Duration::from_millis(50);
+
+        assert_eq!(
+            pick_list.state.poll(due),
+            vec![Deferred::Changed(String::from("ab"))]
+        );
+        assert_eq!(pick_list.state.poll(due), vec![]);
+    }
+
+    #[test]
+    fn debounced_changes_are_flushed_by_later_events() {
+        let mut state = State::focused();
+        let mut pick_list =
+            pick_list(&mut state, None).debounce(Duration::from_millis(50));
+
+        let _ = update(&mut pick_list, type_text("ab"), Point::ORIGIN);
+
+        let mut messages = Vec::new();
+
+        pick_list.flush_change(
+            Instant::now() + Duration::from_millis(50),
+            &mut Shell::new(&mut messages),
+        );
+
+        assert_eq!(messages, vec![Message::Changed(String::from("ab"))]);
+        assert_eq!(pick_list.state.poll(Instant::now()), vec![]);
+    }
+
+    #[test]
+    fn pending_changes_survive_rebuilds() {
+        let mut state = State::focused();
+
+        {
+            let mut pick_list =
+                pick_list(&mut state, None).debounce(Duration::from_secs(60));
+
+            let _ = update(
+                &mut pick_list,
+                [Event::Keyboard(keyboard::Event::CharacterReceived('a'))],
+                Point::ORIGIN,
+            );
+        }
+
+        let pick_list = pick_list::<Null>(&mut state, None);
+
+        assert_eq!(pick_list.value.to_string(), "a");
+    }
+
+    #[test]
+    fn menu_is_hidden_below_min_search_length() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None).min_search_length(2);
+
+        let messages = update(
+            &mut pick_list,
+            [
+                click(),
+                Event::Keyboard(keyboard::Event::CharacterReceived('a')),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(messages, vec![Message::Changed(String::from("a"))]);
+        assert!(pick_list
+            .overlay(Layout::new(&layout::Node::new(Size::ZERO)), &Null)
+            .is_none());
+        assert_eq!(pick_list.visible_options(), &OPTIONS);
+
+        let _ = update(
+            &mut pick_list,
+            [Event::Keyboard(keyboard::Event::CharacterReceived('n'))],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(pick_list.visible_options(), &["Banana"]);
+    }
+
+    /// A renderer measuring every grapheme as 10 units wide and recording
+    /// the contents and colors of the text and the quads it draws.
+    #[derive(Default)]
+    struct Monospace {
+        measured: std::cell::Cell<usize>,
+        drawn: Vec<String>,
+        colors: Vec<Color>,
+        quads: Vec<Rectangle>,
+        backgrounds: Vec<Background>,
+    }
+
+    impl renderer::Renderer for Monospace {
+        fn with_layer(
+            &mut self,
+            _bounds: Rectangle,
+            f: impl FnOnce(&mut Self),
+        ) {
+            f(self);
+        }
+
+        fn with_translation(
+            &mut self,
+            _translation: Vector,
+            f: impl FnOnce(&mut Self),
+        ) {
+            f(self);
+        }
+
+        fn clear(&mut self) {}
+
+        fn fill_quad(
+            &mut self,
+            quad: renderer::Quad,
+            background: impl Into<crate::Background>,
+        ) {
+            self.quads.push(quad.bounds);
+            self.backgrounds.push(background.into());
+        }
+    }
+
+    impl text::Renderer for Monospace {
+        type Font = crate::Font;
+
+        const ICON_FONT: crate::Font = crate::Font::Default;
+        const CHECKMARK_ICON: char = '0';
+        const ARROW_DOWN_ICON: char = '0';
+
+        fn default_size(&self) -> u16 {
+            20
+        }
+
+        fn measure(
+            &self,
+            content: &str,
+            _size: u16,
+            _font: crate::Font,
+            bounds: Size,
+        ) -> (f32, f32) {
+            self.measured.set(self.measured.get() + 1);
+
+            let width = Value::new(content).len() as f32 * 10.0;
+            let lines = (width / bounds.width).ceil().max(1.0);
+
+            (width.min(bounds.width), 20.0 * lines)
+        }
+
+        fn hit_test(
+            &self,
+            _contents: &str,
+            _size: f32,
+            _font: crate::Font,
+            _bounds: Size,
+            _point: Point,
+            _nearest_only: bool,
+        ) -> Option<text::Hit> {
+            None
+        }
+
+        fn fill_text(&mut self, text: Text<'_, crate::Font>) {
+            self.drawn.push(text.content.to_owned());
+            self.colors.push(text.color);
+        }
+    }
+
+    #[test]
+    fn finds_the_nearest_cursor_position() {
+        let value = Value::new("héllo");
+
+        let position = |offset, x| {
+            find_cursor_position_at_offset(
+                &Monospace::default(),
+                crate::Font::Default,
+                None,
+                &value,
+                offset,
+                x,
+            )
+        };
+
+        assert_eq!(position(0.0, -5.0), 0);
+        assert_eq!(position(0.0, 0.0), 0);
+        assert_eq!(position(0.0, 14.0), 1);
+        assert_eq!(position(0.0, 16.0), 2);
+        assert_eq!(position(20.0, 4.0), 2);
+        assert_eq!(position(0.0, 100.0), 5);
+    }
+
+    #[test]
+    fn measurements_are_shared_while_dragging() {
+        let renderer = Monospace::default();
+        let value = Value::new(&"a".repeat(200));
+        let measurements =
+            || Measurements::new(&renderer, crate::Font::Default, 20, &value);
+
+        // Dragging finds the cursor under the pointer and then scrolls to it
+        renderer.measured.set(0);
+
+        let position = measurements().cursor_position(0.0, 1234.0);
+        let _ = measurements().width(position);
+        let _ = measurements().text_width();
+
+        let uncached = renderer.measured.get();
+
+        renderer.measured.set(0);
+
+        let mut shared = measurements();
+
+        assert_eq!(shared.cursor_position(0.0, 1234.0), 123);
+
+        let _ = shared.width(position);
+        let _ = shared.text_width();
+
+        assert!(renderer.measured.get() < uncached);
+    }
+
+    fn type_text(text: &str) -> impl Iterator<Item = Event> + '_ {
+        text.chars()
+            .map(|c| Event::Keyboard(keyboard::Event::CharacterReceived(c)))
+    }
+
+    #[test]
+    fn cursor_steps_over_flag_emoji() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None);
+
+        let _ = update(
+            &mut pick_list,
+            type_text("a\u{1F1EF}\u{1F1F5}b"),
+            Point::ORIGIN,
+        );
+
+        assert_eq!(pick_list.value.len(), 3);
+        assert_eq!(pick_list.state().cursor.end(&pick_list.value), 3);
+
+        let messages = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::Left),
+                key_press(keyboard::KeyCode::Backspace),
+            ],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(messages, vec![Message::Changed(String::from("ab"))]);
+        assert_eq!(pick_list.state().cursor.end(&pick_list.value), 1);
+    }
+
+    #[test]
+    fn combining_diacritics_join_the_previous_grapheme() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None);
+
+        let _ = update(
+            &mut pick_list,
+            type_text("xy")
+                .chain([key_press(keyboard::KeyCode::Left)])
+                .chain(type_text("e\u{301}")),
+            Point::ORIGIN,
+        );
+
+        assert_eq!(pick_list.value.to_string(), "xe\u{301}y");
+        assert_eq!(pick_list.value.len(), 3);
+        assert_eq!(pick_list.state().cursor.end(&pick_list.value), 2);
+
+        let messages = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Backspace)],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(messages, vec![Message::Changed(String::from("xy"))]);
+    }
+
+    #[test]
+    fn finds_the_cursor_position_scrolled_to_the_cursor() {
+        let value = Value::new("héllo");
+        let text_bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 30.0,
+            height: 20.0,
+        };
+
+        let mut cursor = Cursor::default();
+        cursor.move_to(value.len());
+
+        let position = |is_focused| {
+            find_cursor_position(
+                &Monospace::default(),
+                text_bounds,
+                crate::Font::Default,
+                None,
+                &value,
+                is_focused,
+                cursor,
+                4.0,
+            )
+        };
+
+        assert_eq!(position(true), 3);
+        assert_eq!(position(false), 0);
+    }
+
+    #[test]
+    fn cursor_positions_fall_between_graphemes() {
+        let value = Value::new("e\u{301}\u{1F1EF}\u{1F1F5}a");
+
+        let position = find_cursor_position_at_offset(
+            &Monospace::default(),
+            crate::Font::Default,
+            None,
+            &value,
+            0.0,
+            16.0,
+        );
+
+        assert_eq!(position, 2);
+        assert_eq!(
+            value.until(position).to_string(),
+            "e\u{301}\u{1F1EF}\u{1F1F5}"
+        );
+    }
+
+    #[test]
+    fn match_range_ignores_case() {
+        assert_eq!(match_range("Banana", "NAN"), Some(2..5));
+        assert_eq!(match_range("Crème brûlée", "BRÛ"), Some(7..11));
+        assert_eq!(match_range("Apple", "x"), None);
+        assert_eq!(match_range("Apple", ""), None);
+    }
+
+    #[test]
+    fn disabled_pick_lists_ignore_clicks() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None)
+            .enabled(false)
+            .on_open(Message::Opened);
+
+        let messages = update(
+            &mut pick_list,
+            [click(), key_press(keyboard::KeyCode::Down)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert!(messages.is_empty());
+        assert!(!pick_list.state().pick_list.is_open);
+        assert!(!pick_list.state().is_focused());
+    }
+
+    #[test]
+    fn opening_scrolls_to_the_selected_option() {
+        let options: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+
+        let mut state = State::new();
+        let mut pick_list = SearchablePickList::<_, Message, Null>::new(
+            &mut state,
+            "Search...",
+            "",
+            Some(options[80].clone()),
+            &options[..],
+            Message::Changed,
+            |_| Message::Custom,
+        );
+
+        let node = Widget::<Message, Null>::layout(
+            &pick_list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        let _ = pick_list.on_event(
+            click(),
+            Layout::new(&node),
+            Point::new(10.0, 10.0),
+            &Null,
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+        );
+
+        let row_height = 20.0;
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(200.0, 200.0));
+        let content = Rectangle::new(
+            Point::ORIGIN,
+            Size::new(200.0, row_height * options.len() as f32),
+        );
+
+        let offset = pick_list
+            .state()
+            .pick_list
+            .menu
+            .scrollable
+            .offset(viewport, content) as f32;
+
+        let row = 80.0 * row_height;
+
+        assert_eq!(pick_list.state().hovered_index(), Some(80));
+        assert!(offset <= row && row + row_height <= offset + viewport.height);
+    }
+
+    #[test]
+    fn opening_scrolls_to_the_selected_option_past_group_headers() {
+        let options: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+
+        let mut state = State::new();
+        let mut pick_list = SearchablePickList::<_, Message, Null>::new(
+            &mut state,
+            "Search...",
+            "",
+            Some(options[80].clone()),
+            &options[..],
+            Message::Changed,
+            |_| Message::Custom,
+        )
+        .group_by(|option: &String| {
+            Cow::Owned(format!("{}0s", option.parse::<usize>().unwrap() / 10))
+        });
+
+        let node = Widget::<Message, Null>::layout(
+            &pick_list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        let _ = pick_list.on_event(
+            click(),
+            Layout::new(&node),
+            Point::new(10.0, 10.0),
+            &Null,
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+        );
+
+        let row_height = 20.0;
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(200.0, 100.0));
+
+        // Every ten options are preceded by a header
+        let rows = options.len() + 10;
+        let content = Rectangle::new(
+            Point::ORIGIN,
+            Size::new(200.0, row_height * rows as f32),
+        );
+
+        let offset = pick_list
+            .state()
+            .pick_list
+            .menu
+            .scrollable
+            .offset(viewport, content) as f32;
+
+        let row = (80.0 + 9.0) * row_height;
+
+        assert!(offset <= row && row + row_height <= offset + viewport.height);
+    }
+
+    #[test]
+    fn state_keeps_the_value_across_rebuilds() {
+        let mut state = State::new();
+
+        {
+            let mut pick_list = pick_list(&mut state, None);
+            pick_list.state.focus();
+
+            let _ = update(&mut pick_list, type_text("ap"), Point::ORIGIN);
+        }
+
+        assert_eq!(state.value(), "ap");
+
+        state.set_value("Cherry");
+
+        let mut pick_list = pick_list(&mut state, None);
+
+        assert_eq!(pick_list.value.to_string(), "Cherry");
+
+        let messages = update(
+            &mut pick_list,
+            [Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::ORIGIN,
+            })],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(messages, vec![Message::Changed(String::from("Cherry"))]);
+    }
+
+    #[test]
+    fn focus_changes_are_reported() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None)
+            .on_focus(Message::Opened)
+            .on_unfocus(Message::Closed);
+
+        let inside = Point::new(10.0, 10.0);
+        let outside = Point::new(10.0, 90.0);
+
+        assert_eq!(
+            update(&mut pick_list, [click()], inside),
+            vec![Message::Opened]
+        );
+        assert!(update(&mut pick_list, [click()], inside).is_empty());
+        assert_eq!(
+            update(&mut pick_list, [click()], outside),
+            vec![Message::Closed]
+        );
+
+        let _ = update(&mut pick_list, [click()], inside);
+
+        assert_eq!(
+            update(
+                &mut pick_list,
+                [key_press(keyboard::KeyCode::Escape)],
+                inside
+            ),
+            vec![Message::Closed]
+        );
+    }
+
+    #[test]
+    fn draws_the_custom_icon() {
+        let mut renderer = Monospace::default();
+
+        for is_open in [false, true] {
+            let mut state = State::new();
+            state.pick_list.is_open = is_open;
+
+            let pick_list = SearchablePickList::new(
+                &mut state,
+                "Search...",
+                "",
+                None,
+                &OPTIONS[..],
+                Message::Changed,
+                Message::Selected,
+            )
+            .icon('?', crate::Font::Default);
+
+            let node = Widget::<Message, Monospace>::layout(
+                &pick_list,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            );
+
+            renderer.drawn.clear();
+            pick_list.draw(
+                &mut renderer,
+                Layout::new(&node),
+                Point::ORIGIN,
+                None,
+            );
+
+            assert!(renderer.drawn.contains(&String::from("?")));
+            assert!(!renderer.drawn.contains(&String::from("0")));
+        }
+    }
+
+    #[test]
+    fn ellipsizes_overflowing_labels() {
+        let renderer = Monospace::default();
+        let ellipsize = |label, max_width| {
+            ellipsize(&renderer, label, 20, &crate::Font::Default, max_width)
+        };
+
+        assert_eq!(ellipsize("Banana", 60.0), "Banana");
+        assert_eq!(ellipsize("Banana", 45.0), "Ban…");
+        assert_eq!(ellipsize("Banana split", 75.0), "Banana…");
+        assert_eq!(ellipsize("Banana", 5.0), "…");
+    }
+
+    #[test]
+    fn shows_loading_message_instead_of_empty_message() {
+        let mut renderer = Monospace::default();
+
+        for (loading, message) in [(false, "No fruits"), (true, "Loading…")] {
+            let mut state = State::new();
+            state.pick_list.is_open = true;
+
+            let mut pick_list = SearchablePickList::new(
+                &mut state,
+                "Search...",
+                "",
+                None,
+                &[][..],
+                Message::Changed,
+                Message::Selected,
+            )
+            .options_empty_message(String::from("No fruits"))
+            .loading(loading);
+
+            let node = Widget::<Message, Monospace>::layout(
+                &pick_list,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            );
+
+            let menu = Widget::<Message, Monospace>::overlay(
+                &mut pick_list,
+                Layout::new(&node),
+                &renderer,
+            )
+            .unwrap();
+
+            let menu_node = menu.layout(&renderer, Size::new(200.0, 400.0));
+
+            renderer.drawn.clear();
+            menu.draw(
+                &mut renderer,
+                &renderer::Style::default(),
+                Layout::new(&menu_node),
+                Point::ORIGIN,
+            );
+
+            assert_eq!(renderer.drawn, [message]);
+        }
+    }
+
+    #[test]
+    fn highlights_matching_spans_by_default() {
+        let mut renderer = Monospace::default();
+
+        for (mode, label) in [
+            (None, &["B", "an", "ana"][..]),
+            (Some(MatchHighlightMode::None), &["Banana"][..]),
+        ] {
+            let mut state = State::new();
+            state.pick_list.is_open = true;
+
+            let mut pick_list = SearchablePickList::new(
+                &mut state,
+                "Search...",
+                "an",
+                None,
+                &OPTIONS[..],
+                Message::Changed,
+                Message::Selected,
+            );
+
+            if let Some(mode) = mode {
+                pick_list = pick_list.match_highlight_mode(mode);
+            }
+
+            let node = Widget::<Message, Monospace>::layout(
+                &pick_list,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            );
+
+            let menu = Widget::<Message, Monospace>::overlay(
+                &mut pick_list,
+                Layout::new(&node),
+                &renderer,
+            )
+            .unwrap();
+
+            let menu_node = menu.layout(&renderer, Size::new(200.0, 400.0));
+
+            renderer.drawn.clear();
+            menu.draw(
+                &mut renderer,
+                &renderer::Style::default(),
+                Layout::new(&menu_node),
+                Point::ORIGIN,
+            );
+
+            assert!(renderer
+                .drawn
+                .windows(label.len())
+                .any(|drawn| drawn == label));
+        }
+    }
+
+    #[test]
+    fn keyboard_navigation_skips_disabled_options() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, Some("Banana"))
+            .option_enabled(|option| *option != "Banana");
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        assert_eq!(pick_list.state().pick_list.hovered_option, None);
+
+        let _ = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Down),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(pick_list.state().pick_list.hovered_option, Some(2));
+
+        let _ = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Up)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(pick_list.state().pick_list.hovered_option, Some(0));
+    }
+
+    #[test]
+    fn page_keys_move_the_hovered_option_by_a_page() {
+        let options = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+        let mut state = State::new();
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "",
+            None,
+            &options[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .menu_max_height(60);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        let mut hover = |key_code| {
+            let _ = update(
+                &mut pick_list,
+                [key_press(key_code)],
+                Point::new(10.0, 10.0),
+            );
+
+            pick_list.state().pick_list.hovered_option
+        };
+
+        assert_eq!(hover(keyboard::KeyCode::PageDown), Some(0));
+        assert_eq!(hover(keyboard::KeyCode::PageDown), Some(3));
+        assert_eq!(hover(keyboard::KeyCode::PageDown), Some(6));
+        assert_eq!(hover(keyboard::KeyCode::PageDown), Some(9));
+        assert_eq!(hover(keyboard::KeyCode::PageDown), Some(9));
+        assert_eq!(hover(keyboard::KeyCode::PageUp), Some(6));
+        assert_eq!(hover(keyboard::KeyCode::Home), Some(0));
+        assert_eq!(hover(keyboard::KeyCode::PageUp), Some(0));
+        assert_eq!(hover(keyboard::KeyCode::End), Some(9));
+    }
+
+    #[test]
+    fn alignment_offset_only_shifts_fitting_text() {
+        use alignment::Horizontal;
+
+        assert_eq!(alignment_offset(Horizontal::Left, 30.0, 100.0), 0.0);
+        assert_eq!(alignment_offset(Horizontal::Center, 30.0, 100.0), 35.0);
+        assert_eq!(alignment_offset(Horizontal::Right, 30.0, 100.0), 70.0);
+        assert_eq!(alignment_offset(Horizontal::Right, 130.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn clicks_find_the_cursor_in_right_aligned_text() {
+        let renderer = Monospace::default();
+
+        let mut state = State::focused();
+        state.pick_list.is_open = true;
+
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "abc",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .text_alignment(alignment::Horizontal::Right);
+
+        let node = Widget::<Message, Monospace>::layout(
+            &pick_list,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        // The text is 30 units wide and ends where the arrow starts, at 170
+        let position = Point::new(162.0, 10.0);
+        let mut messages = Vec::new();
+
+        let _ = pick_list.on_event(
+            click(),
+            Layout::new(&node),
+            position,
+            &renderer,
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+        );
+
+        assert_eq!(pick_list.state().cursor.end(&Value::new("abc")), 2);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn middle_click_pastes_the_primary_selection() {
+        /// A clipboard holding different contents in its primary selection.
+        struct Selection;
+
+        impl Clipboard for Selection {
+            fn read(&self) -> Option<String> {
+                Some(String::from("clipboard"))
+            }
+
+            fn write(&mut self, _contents: String) {}
+
+            fn read_primary(&self) -> Option<String> {
+                Some(String::from("selection"))
+            }
+        }
+
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None);
+        pick_list.value = Value::new("abcd");
+
+        let messages = update_with(
+            &mut pick_list,
+            &Monospace::default(),
+            &mut Selection,
+            [Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            ))],
+            Point::new(20.0, 10.0),
+        );
+
+        assert!(pick_list.state().is_focused());
+        assert_eq!(
+            messages.first(),
+            Some(&Message::Changed(String::from("abselectioncd")))
+        );
+    }
+
+    #[test]
+    fn pasting_flattens_line_breaks_and_tabs() {
+        /// A clipboard holding multi-line contents.
+        struct MultiLine;
+
+        impl Clipboard for MultiLine {
+            fn read(&self) -> Option<String> {
+                Some(String::from("a\nb\tc\r\nd\u{7}"))
+            }
 
-                if let Some(last_selection) =
-                    self.state.pick_list.last_selection.take()
-                {
-                    shell.publish((self.on_selected)(last_selection));
+            fn write(&mut self, _contents: String) {}
+        }
 
-                    self.state.pick_list.is_open = false;
-                    self.state.is_focused = false;
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None);
 
-                    return event::Status::Captured;
-                } else {
-                    return event_status;
-                }
+        let node = Widget::<Message, Null>::layout(
+            &pick_list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        for event in [
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::COMMAND,
+            )),
+            key_press(keyboard::KeyCode::V),
+        ] {
+            let _ = pick_list.on_event(
+                event,
+                Layout::new(&node),
+                Point::ORIGIN,
+                &Null,
+                &mut MultiLine,
+                &mut Shell::new(&mut messages),
+            );
+        }
+
+        assert_eq!(messages, [Message::Changed(String::from("a b c d"))]);
+    }
+
+    #[test]
+    fn typing_over_a_selection_can_be_undone() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None);
+        pick_list.value = Value::new("Banana");
+
+        let modifiers = |modifiers| {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers))
+        };
+
+        let events = [
+            modifiers(keyboard::Modifiers::COMMAND),
+            key_press(keyboard::KeyCode::A),
+            modifiers(keyboard::Modifiers::default()),
+            Event::Keyboard(keyboard::Event::CharacterReceived('x')),
+            Event::Keyboard(keyboard::Event::CharacterReceived('y')),
+            modifiers(keyboard::Modifiers::COMMAND),
+            key_press(keyboard::KeyCode::Z),
+            modifiers(
+                keyboard::Modifiers::COMMAND | keyboard::Modifiers::SHIFT,
+            ),
+            key_press(keyboard::KeyCode::Z),
+        ];
+
+        assert_eq!(
+            update(&mut pick_list, events, Point::ORIGIN),
+            [
+                Message::Changed(String::from("x")),
+                Message::Changed(String::from("xy")),
+                Message::Changed(String::from("Banana")),
+                Message::Changed(String::from("xy")),
+            ]
+        );
+    }
+
+    #[test]
+    fn copies_and_cuts_the_whole_line_without_a_selection() {
+        /// A clipboard keeping the last contents written to it.
+        #[derive(Default)]
+        struct Memory(Option<String>);
+
+        impl Clipboard for Memory {
+            fn read(&self) -> Option<String> {
+                self.0.clone()
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                self.state.is_dragging = false;
+
+            fn write(&mut self, contents: String) {
+                self.0 = Some(contents);
             }
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
-                if self.state.is_dragging {
-                    let text_layout = layout.children().next().unwrap();
-                    let target = position.x - text_layout.bounds().x;
+        }
 
-                    if target > 0.0 {
-                        let value = self.value.clone();
-
-                        let position = find_cursor_position(
-                            renderer,
-                            text_layout.bounds(),
-                            self.font.clone(),
-                            self.size,
-                            &value,
-                            self.state.is_focused,
-                            self.state.cursor,
-                            target,
-                        );
+        for (whole_line, copied, cut) in
+            [(false, None, "Banan"), (true, Some("Banana"), "")]
+        {
+            let mut state = State::focused();
+            state.cursor.move_to(5);
 
-                        self.state.cursor.select_range(
-                            self.state.cursor.start(&value),
-                            position,
-                        );
-                    }
+            let mut pick_list = pick_list(&mut state, None)
+                .whole_line_on_no_selection(whole_line);
+            pick_list.value = Value::new("Banana");
 
-                    return event::Status::Captured;
-                }
+            let mut clipboard = Memory::default();
+            let mut press = |events: Vec<Event>, clipboard: &mut Memory| {
+                update_with(
+                    &mut pick_list,
+                    &Null,
+                    clipboard,
+                    events,
+                    Point::ORIGIN,
+                )
+            };
+
+            let messages = press(
+                vec![
+                    Event::Keyboard(keyboard::Event::ModifiersChanged(
+                        keyboard::Modifiers::COMMAND,
+                    )),
+                    key_press(keyboard::KeyCode::C),
+                ],
+                &mut clipboard,
+            );
+
+            assert_eq!(clipboard.0.as_deref(), copied);
+            assert_eq!(messages, []);
+
+            clipboard.0 = None;
+            let messages =
+                press(vec![key_press(keyboard::KeyCode::X)], &mut clipboard);
+
+            assert_eq!(clipboard.0.as_deref(), copied);
+            assert_eq!(messages, [Message::Changed(String::from(cut))]);
+        }
+    }
+
+    #[test]
+    fn caret_blinks() {
+        let since = Instant::now();
+
+        assert!(is_caret_visible(None, since));
+        assert!(is_caret_visible(Some(since), since));
+        assert!(!is_caret_visible(
+            Some(since),
+            since + CARET_BLINK_INTERVAL + CARET_BLINK_INTERVAL / 2
+        ));
+        assert!(is_caret_visible(
+            Some(since),
+            since + CARET_BLINK_INTERVAL * 2
+        ));
+    }
+
+    #[test]
+    fn typing_restarts_the_caret_blink() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None);
+
+        let _ = update(
+            &mut pick_list,
+            [Event::Keyboard(keyboard::Event::CharacterReceived('a'))],
+            Point::ORIGIN,
+        );
+
+        let since = pick_list.state.caret_since.expect("Caret moved");
+
+        let _ = update(
+            &mut pick_list,
+            [Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::ORIGIN,
+            })],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(pick_list.state.caret_since, Some(since));
+
+        pick_list.state.caret_since = None;
+
+        let _ = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Left)],
+            Point::ORIGIN,
+        );
+
+        assert!(pick_list.state.caret_since.is_some());
+    }
+
+    #[test]
+    fn loading_needs_ticks() {
+        let mut state = State::new();
+        let _ = pick_list::<Null>(&mut state, None).loading(true);
+
+        assert!(state.needs_tick());
+
+        let since = state.loading_since;
+        let _ = pick_list::<Null>(&mut state, None).loading(true);
+
+        assert_eq!(state.loading_since, since);
+
+        let _ = pick_list::<Null>(&mut state, None);
+
+        assert!(!state.needs_tick());
+    }
+
+    #[test]
+    fn draws_the_spinner_while_loading() {
+        let mut renderer = Monospace::default();
+
+        for loading in [false, true] {
+            let mut state = State::new();
+
+            let pick_list = SearchablePickList::new(
+                &mut state,
+                "Search...",
+                "",
+                None,
+                &OPTIONS[..],
+                Message::Changed,
+                Message::Selected,
+            )
+            .loading(loading);
+
+            let node = Widget::<Message, Monospace>::layout(
+                &pick_list,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            );
+
+            renderer.drawn.clear();
+            pick_list.draw(
+                &mut renderer,
+                Layout::new(&node),
+                Point::ORIGIN,
+                None,
+            );
+
+            let is_spinning = renderer.drawn.iter().any(|content| {
+                SPINNER_FRAMES
+                    .iter()
+                    .any(|frame| *content == frame.to_string())
+            });
+
+            assert_eq!(is_spinning, loading);
+        }
+    }
+
+    #[test]
+    fn blinking_caret_needs_ticks() {
+        for blink in [true, false] {
+            let mut state = State::focused();
+            let mut pick_list = pick_list(&mut state, None).caret_blink(blink);
+
+            let _ = update(&mut pick_list, type_text("a"), Point::ORIGIN);
+
+            assert_eq!(pick_list.state().needs_tick(), blink);
+
+            pick_list.state.unfocus();
+
+            assert!(!pick_list.state().needs_tick());
+        }
+    }
+
+    #[test]
+    fn tab_releases_the_focus() {
+        for modifiers in
+            [keyboard::Modifiers::default(), keyboard::Modifiers::SHIFT]
+        {
+            let mut state = State::new();
+            let mut pick_list = pick_list(&mut state, None);
+
+            let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+            assert!(pick_list.state().is_open());
+
+            let node = Widget::<Message, Null>::layout(
+                &pick_list,
+                &Null,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            );
+
+            let mut messages = Vec::new();
+            let mut status = event::Status::Captured;
+
+            for event in [
+                Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)),
+                key_press(keyboard::KeyCode::Tab),
+            ] {
+                status = pick_list.on_event(
+                    event,
+                    Layout::new(&node),
+                    Point::new(10.0, 10.0),
+                    &Null,
+                    &mut clipboard::Null,
+                    &mut Shell::new(&mut messages),
+                );
             }
-            Event::Keyboard(keyboard::Event::CharacterReceived(c))
-                if self.state.is_focused
-                    && self.state.is_pasting.is_none()
-                    && !self.state.keyboard_modifiers.command()
-                    && !c.is_control() =>
-            {
-                let mut editor =
-                    Editor::new(&mut self.value, &mut self.state.cursor);
 
-                editor.insert(c);
+            assert_eq!(status, event::Status::Ignored);
+            assert!(!pick_list.state().is_open());
+            assert!(!pick_list.state().is_focused());
+        }
+    }
 
-                let message = (self.on_change)(editor.contents());
-                shell.publish(message);
+    #[test]
+    fn dragging_the_menu_scrollbar_does_not_select_text() {
+        let mut state = State::focused();
+        state.pick_list.is_open = true;
+        let mut pick_list = pick_list(&mut state, None);
+        pick_list.value = Value::new("Banana");
 
-                return event::Status::Captured;
+        // Presses over the menu reach the field with an unavailable cursor
+        let _ = update(&mut pick_list, [click()], Point::new(-1.0, -1.0));
+        let _ = update(
+            &mut pick_list,
+            [Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(40.0, 10.0),
+            })],
+            Point::new(40.0, 10.0),
+        );
+
+        assert!(pick_list.state().is_open());
+        assert!(!pick_list.state().is_dragging);
+        assert_eq!(
+            pick_list.state().cursor().selection(&pick_list.value),
+            None
+        );
+    }
+
+    #[test]
+    fn type_ahead_selects_options_while_closed() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None).type_ahead(true);
+
+        let type_ahead = |text: &str| {
+            text.chars()
+                .map(|c| Event::Keyboard(keyboard::Event::CharacterReceived(c)))
+                .collect::<Vec<_>>()
+        };
+
+        let messages = update(&mut pick_list, type_ahead("b"), Point::ORIGIN);
+
+        assert_eq!(messages, vec![Message::Selected("Banana")]);
+        assert_eq!(pick_list.selected, Some("Banana"));
+        assert_eq!(pick_list.value.to_string(), "");
+        assert!(!pick_list.state().is_open());
+        assert!(pick_list.state().is_focused());
+
+        // "bc" matches nothing, so the selection is kept
+        let messages = update(&mut pick_list, type_ahead("c"), Point::ORIGIN);
+
+        assert_eq!(messages, vec![]);
+        assert_eq!(pick_list.selected, Some("Banana"));
+
+        // The typed characters are forgotten after the timeout
+        pick_list.state.type_ahead = pick_list
+            .state
+            .type_ahead
+            .take()
+            .map(|(typed, _)| (typed, Instant::now() - TYPE_AHEAD_TIMEOUT));
+
+        let messages = update(&mut pick_list, type_ahead("ch"), Point::ORIGIN);
+
+        assert_eq!(messages, vec![Message::Selected("Cherry")]);
+    }
+
+    #[test]
+    fn options_iter_only_collects_matches_while_filtered() {
+        let mut state = State::focused();
+
+        let mut pick_list =
+            pick_list(&mut state, None).options_iter(OPTIONS.iter().copied());
+
+        assert_eq!(pick_list.options.as_ref(), &OPTIONS[..]);
+
+        let _ = update(
+            &mut pick_list,
+            [Event::Keyboard(keyboard::Event::CharacterReceived('r'))],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(pick_list.visible_options(), &["Cherry"][..]);
+
+        drop(pick_list);
+
+        let pick_list = SearchablePickList::<_, _, Null>::new(
+            &mut state,
+            "Search...",
+            "r",
+            None,
+            &[][..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .options_iter(OPTIONS.iter().copied());
+
+        assert_eq!(pick_list.options.as_ref(), &["Cherry"][..]);
+        assert_eq!(pick_list.visible_options(), &["Cherry"][..]);
+    }
+
+    #[test]
+    fn menu_min_width_only_widens_the_menu() {
+        let menu_bounds = |min_width| {
+            let mut state = State::new();
+            state.pick_list.is_open = true;
+
+            let mut pick_list = pick_list(&mut state, None)
+                .width(Length::Units(80))
+                .menu_min_width(min_width);
+
+            let node = Widget::<Message, Null>::layout(
+                &pick_list,
+                &Null,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            );
+
+            let menu = Widget::<Message, Null>::overlay(
+                &mut pick_list,
+                Layout::new(&node),
+                &Null,
+            )
+            .expect("Menu is open");
+
+            menu.layout(&Null, Size::new(400.0, 400.0)).bounds()
+        };
+
+        assert_eq!(menu_bounds(50).width, 80.0);
+        assert_eq!(menu_bounds(150).width, 150.0);
+        assert_eq!(menu_bounds(150).x, 0.0);
+    }
+
+    #[test]
+    fn down_opens_the_focused_closed_menu() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, Some("Banana"));
+
+        let _ = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Down)],
+            Point::ORIGIN,
+        );
+
+        assert!(pick_list.state().is_open());
+        assert_eq!(pick_list.state().hovered_index(), Some(1));
+    }
+
+    #[test]
+    fn space_opens_the_focused_closed_menu_when_not_editable() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None).editable(false);
+
+        let _ = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Space)],
+            Point::ORIGIN,
+        );
+
+        assert!(pick_list.state().is_open());
+    }
+
+    #[test]
+    fn selected_index_overrides_the_selected_option() {
+        let mut state = State::new();
+        let pick_list = pick_list::<Null>(&mut state, Some("Apple"))
+            .selected_index(Some(2));
+
+        assert_eq!(pick_list.selected, Some("Cherry"));
+
+        let mut state = State::new();
+        let mut pick_list = SearchablePickList::<_, _, Null>::new(
+            &mut state,
+            "Search...",
+            "",
+            Some("Apple"),
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .selected_index(Some(3));
+
+        assert_eq!(pick_list.selected, None);
+
+        pick_list = pick_list.selected_index(Some(1));
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        assert_eq!(pick_list.state().hovered_index(), Some(1));
+    }
+
+    #[test]
+    fn menu_max_rows_limits_the_menu_height() {
+        let menu_height = |max_height: Option<u16>| {
+            let mut state = State::new();
+            state.pick_list.is_open = true;
+
+            let mut pick_list = pick_list(&mut state, None)
+                .padding(5)
+                .menu_text_size(20)
+                .menu_max_rows(2);
+
+            if let Some(max_height) = max_height {
+                pick_list = pick_list.menu_max_height(max_height);
             }
-            Event::Keyboard(keyboard::Event::KeyPressed {
-                key_code, ..
-            }) if self.state.is_focused => {
-                let modifiers = self.state.keyboard_modifiers;
 
-                match key_code {
-                    keyboard::KeyCode::Enter => {
-                        if let Some(on_submit) = self.on_submit.clone() {
-                            shell.publish(on_submit);
-                        }
-                    }
-                    keyboard::KeyCode::Backspace => {
-                        if platform::is_jump_modifier_pressed(modifiers)
-                            && self
-                                .state
-                                .cursor
-                                .selection(&self.value)
-                                .is_none()
-                        {
-                            self.state.cursor.select_left_by_words(&self.value);
-                        }
+            let node = Widget::<Message, Null>::layout(
+                &pick_list,
+                &Null,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            );
 
-                        let mut editor = Editor::new(
-                            &mut self.value,
-                            &mut self.state.cursor,
-                        );
+            let menu = Widget::<Message, Null>::overlay(
+                &mut pick_list,
+                Layout::new(&node),
+                &Null,
+            )
+            .expect("Menu is open");
+
+            menu.layout(&Null, Size::new(400.0, 400.0)).bounds().height
+        };
+
+        // Two rows of 30 units, plus the border of the menu
+        assert_eq!(menu_height(None), 62.0);
+        assert_eq!(menu_height(Some(50)), 50.0);
+        assert_eq!(menu_height(Some(80)), 62.0);
+    }
+
+    #[test]
+    fn state_reports_the_interaction_in_progress() {
+        let mut state = State::focused();
+        state.pick_list.is_open = true;
+        let mut pick_list = pick_list(&mut state, None);
+        pick_list.value = Value::new("Banana");
+
+        let _ = update(
+            &mut pick_list,
+            [
+                click(),
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: Point::new(40.0, 10.0),
+                }),
+            ],
+            Point::new(20.0, 10.0),
+        );
+
+        assert!(pick_list.state().is_dragging());
+        assert_eq!(pick_list.state().last_selection(), None);
+
+        let _ = update(
+            &mut pick_list,
+            [Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left,
+            ))],
+            Point::new(40.0, 10.0),
+        );
+
+        assert!(!pick_list.state().is_dragging());
+    }
+
+    #[test]
+    fn escape_restores_the_selected_text() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, Some("Banana"))
+            .restore_on_escape(true)
+            .on_close(Message::Closed);
+
+        let messages = update(
+            &mut pick_list,
+            [
+                click(),
+                Event::Keyboard(keyboard::Event::CharacterReceived('c')),
+                key_press(keyboard::KeyCode::Escape),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Changed(String::from("c")),
+                Message::Changed(String::from("Banana")),
+                Message::Closed,
+            ]
+        );
+        assert_eq!(pick_list.value.to_string(), "Banana");
+        assert!(!pick_list.state().is_open());
+    }
+
+    #[test]
+    fn escape_empties_the_text_without_a_selection() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None).restore_on_escape(true);
+        pick_list.value = Value::new("ch");
+
+        let messages = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Escape)],
+            Point::ORIGIN,
+        );
+
+        assert_eq!(messages, vec![Message::Changed(String::new())]);
+    }
+
+    #[test]
+    fn multi_select_toggles_options_without_closing() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None)
+            .multi_select(true)
+            .on_toggle(Message::Toggled);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        let messages = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Enter),
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Enter),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Toggled("Apple", true),
+                Message::Toggled("Banana", true),
+            ]
+        );
+        assert_eq!(pick_list.state().selected_options(), &["Apple", "Banana"]);
+        assert!(pick_list.state().is_open());
+        assert_eq!(pick_list.selected, None);
+
+        pick_list.state.pick_list.last_selection = Some("Apple");
+
+        let messages =
+            update(&mut pick_list, [click()], Point::new(-1.0, -1.0));
+
+        assert_eq!(messages, vec![Message::Toggled("Apple", false)]);
+        assert_eq!(pick_list.state().selected_options(), &["Banana"]);
+        assert!(pick_list.state().is_open());
+    }
+
+    #[test]
+    fn multi_select_summarizes_the_selected_options() {
+        let mut renderer = Monospace::default();
+        let mut state = State::new();
+        state.set_selected_options(vec!["Apple", "Cherry"]);
+
+        let pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .multi_select(true);
+
+        let node = Widget::<Message, Monospace>::layout(
+            &pick_list,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+        );
+
+        pick_list.draw(&mut renderer, Layout::new(&node), Point::ORIGIN, None);
+
+        assert!(renderer.drawn.contains(&String::from("Apple, Cherry")));
+    }
+
+    #[test]
+    fn wrapped_labels_grow_the_closed_pick_list() {
+        let renderer = Monospace::default();
+
+        let height = |state: &mut State<&'static str>, wrap| {
+            let pick_list = SearchablePickList::new(
+                state,
+                "Search...",
+                "",
+                Some("Banana"),
+                &OPTIONS[..],
+                Message::Changed,
+                Message::Selected,
+            )
+            .width(Length::Units(70))
+            .wrap(wrap);
+
+            Widget::<Message, Monospace>::layout(
+                &pick_list,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            )
+            .size()
+            .height
+        };
+
+        let mut state = State::new();
+
+        assert_eq!(height(&mut state, false), 20.0);
+        assert_eq!(height(&mut state, true), 40.0);
+
+        state.open();
+
+        assert_eq!(height(&mut state, true), 20.0);
+    }
+
+    #[test]
+    fn on_filtered_reports_the_amount_of_matches() {
+        let mut state = State::new();
+        let mut pick_list =
+            pick_list(&mut state, None).on_filtered(Message::Filtered);
+
+        let messages = update(
+            &mut pick_list,
+            [click()].into_iter().chain("ana".chars().map(|c| {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            })),
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Changed(String::from("a")),
+                Message::Filtered(2),
+                Message::Changed(String::from("an")),
+                Message::Filtered(1),
+                Message::Changed(String::from("ana")),
+            ]
+        );
+    }
+
+    #[test]
+    fn on_filtered_is_debounced_with_on_change() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None)
+            .debounce(Duration::from_secs(60))
+            .on_filtered(Message::Filtered);
+
+        let messages = update(
+            &mut pick_list,
+            [Event::Keyboard(keyboard::Event::CharacterReceived('c'))],
+            Point::ORIGIN,
+        );
 
-                        editor.backspace();
+        assert_eq!(messages, vec![]);
 
-                        let message = (self.on_change)(editor.contents());
-                        shell.publish(message);
-                    }
-                    keyboard::KeyCode::Delete => {
-                        if platform::is_jump_modifier_pressed(modifiers)
-                            && self
-                                .state
-                                .cursor
-                                .selection(&self.value)
-                                .is_none()
-                        {
-                            self.state
-                                .cursor
-                                .select_right_by_words(&self.value);
-                        }
+        let mut messages = Vec::new();
 
-                        let mut editor = Editor::new(
-                            &mut self.value,
-                            &mut self.state.cursor,
-                        );
+        pick_list.flush_change(
+            Instant::now() + Duration::from_secs(60),
+            &mut Shell::new(&mut messages),
+        );
 
-                        editor.delete();
+        assert_eq!(
+            messages,
+            vec![Message::Changed(String::from("c")), Message::Filtered(1)]
+        );
+    }
 
-                        let message = (self.on_change)(editor.contents());
-                        shell.publish(message);
-                    }
-                    keyboard::KeyCode::Left => {
-                        if platform::is_jump_modifier_pressed(modifiers) {
-                            if modifiers.shift() {
-                                self.state
-                                    .cursor
-                                    .select_left_by_words(&self.value);
-                            } else {
-                                self.state
-                                    .cursor
-                                    .move_left_by_words(&self.value);
-                            }
-                        } else if modifiers.shift() {
-                            self.state.cursor.select_left(&self.value)
-                        } else {
-                            self.state.cursor.move_left(&self.value);
-                        }
-                    }
-                    keyboard::KeyCode::Right => {
-                        if platform::is_jump_modifier_pressed(modifiers) {
-                            if modifiers.shift() {
-                                self.state
-                                    .cursor
-                                    .select_right_by_words(&self.value);
-                            } else {
-                                self.state
-                                    .cursor
-                                    .move_right_by_words(&self.value);
-                            }
-                        } else if modifiers.shift() {
-                            self.state.cursor.select_right(&self.value)
-                        } else {
-                            self.state.cursor.move_right(&self.value);
-                        }
-                    }
-                    keyboard::KeyCode::Home => {
-                        if modifiers.shift() {
-                            self.state.cursor.select_range(
-                                self.state.cursor.start(&self.value),
-                                0,
-                            );
-                        } else {
-                            self.state.cursor.move_to(0);
-                        }
-                    }
-                    keyboard::KeyCode::End => {
-                        if modifiers.shift() {
-                            self.state.cursor.select_range(
-                                self.state.cursor.start(&self.value),
-                                self.value.len(),
-                            );
-                        } else {
-                            self.state.cursor.move_to(self.value.len());
-                        }
-                    }
-                    keyboard::KeyCode::C
-                        if self.state.keyboard_modifiers.command() =>
-                    {
-                        match self.state.cursor.selection(&self.value) {
-                            Some((start, end)) => {
-                                clipboard.write(
-                                    self.value.select(start, end).to_string(),
-                                );
-                            }
-                            None => {}
-                        }
-                    }
-                    keyboard::KeyCode::X
-                        if self.state.keyboard_modifiers.command() =>
-                    {
-                        match self.state.cursor.selection(&self.value) {
-                            Some((start, end)) => {
-                                clipboard.write(
-                                    self.value.select(start, end).to_string(),
-                                );
-                            }
-                            None => {}
-                        }
+    #[test]
+    fn debounced_filtered_counts_are_polled() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None)
+            .debounce(Duration::from_secs(60))
+            .on_filtered(Message::Filtered);
 
-                        let mut editor = Editor::new(
-                            &mut self.value,
-                            &mut self.state.cursor,
-                        );
+        let messages = update(
+            &mut pick_list,
+            [Event::Keyboard(keyboard::Event::CharacterReceived('c'))],
+            Point::ORIGIN,
+        );
 
-                        editor.delete();
+        assert_eq!(messages, vec![]);
 
-                        let message = (self.on_change)(editor.contents());
-                        shell.publish(message);
-                    }
-                    keyboard::KeyCode::V => {
-                        if self.state.keyboard_modifiers.command() {
-                            let content = match self.state.is_pasting.take() {
-                                Some(content) => content,
-                                None => {
-                                    let content: String = clipboard
-                                        .read()
-                                        .unwrap_or(String::new())
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
-
-                                    Value::new(&content)
-                                }
-                            };
+        let due = Instant::now() + Duration::from_secs(60);
 
-                            let mut editor = Editor::new(
-                                &mut self.value,
-                                &mut self.state.cursor,
-                            );
+        assert_eq!(
+            pick_list.state.poll(due),
+            vec![Deferred::Changed(String::from("c")), Deferred::Filtered(1)]
+        );
+        assert_eq!(pick_list.state.poll(due), vec![]);
 
-                            editor.paste(content.clone());
+        let messages = update(
+            &mut pick_list,
+            [Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::ORIGIN,
+            })],
+            Point::ORIGIN,
+        );
 
-                            let message = (self.on_change)(editor.contents());
-                            shell.publish(message);
+        assert_eq!(messages, vec![]);
+    }
 
-                            self.state.is_pasting = Some(content);
-                        } else {
-                            self.state.is_pasting = None;
-                        }
-                    }
-                    keyboard::KeyCode::A
-                        if self.state.keyboard_modifiers.command() =>
-                    {
-                        self.state.cursor.select_all(&self.value);
-                    }
-                    keyboard::KeyCode::Escape => {
-                        self.state.is_focused = false;
-                        self.state.is_dragging = false;
-                        self.state.is_pasting = None;
+    #[test]
+    fn arrow_follows_the_right_padding() {
+        let bounds = Rectangle {
+            x: 10.0,
+            y: 0.0,
+            width: 200.0,
+            height: 40.0,
+        };
 
-                        self.state.keyboard_modifiers =
-                            keyboard::Modifiers::default();
-                    }
-                    _ => {}
-                }
+        let padding = Padding {
+            top: 0,
+            right: 20,
+            bottom: 0,
+            left: 4,
+        };
 
-                return event::Status::Captured;
-            }
-            Event::Keyboard(keyboard::Event::KeyReleased {
-                key_code, ..
-            }) if self.state.is_focused => {
-                match key_code {
-                    keyboard::KeyCode::V => {
-                        self.state.is_pasting = None;
-                    }
-                    _ => {}
-                }
+        let arrow = arrow_bounds(bounds, padding, ARROW_WIDTH, false);
 
-                return event::Status::Captured;
-            }
-            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers))
-                if self.state.is_focused =>
-            {
-                self.state.keyboard_modifiers = modifiers;
-            }
-            _ => {}
-        }
+        assert_eq!(arrow.x, 10.0 + 200.0 - 20.0 - ARROW_WIDTH);
+        assert_eq!(arrow.x + arrow.width, bounds.x + bounds.width);
+        assert_eq!(
+            clear_icon_bounds(bounds, padding, ARROW_WIDTH, false).x,
+            arrow.x - CLEAR_ICON_WIDTH
+        );
+    }
 
-        event::Status::Ignored
+    #[test]
+    fn arrow_reserved_width_moves_the_clear_icon() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, Some("Apple"))
+            .on_clear(Message::Cleared)
+            .arrow_reserved_width(50.0);
+        pick_list.value = Value::new("Apple");
+
+        let messages =
+            update(&mut pick_list, [click()], Point::new(200.0 - 60.0, 10.0));
+
+        assert!(messages.contains(&Message::Cleared));
     }
 
-    fn draw(
-        &self,
-        renderer: &mut Renderer,
-        _style: &renderer::Style,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        _viewport: &Rectangle,
-    ) {
-        self.draw(renderer, layout, cursor_position, None)
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_round_trips_through_serde() {
+        let mut state = State::<String>::focused();
+        state.set_value("Ban");
+        state.move_cursor_to(2);
+        state.set_selected_options(vec![String::from("Apple")]);
+
+        let snapshot = state.snapshot(Some(&String::from("Banana")));
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let deserialized: Snapshot<String> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, snapshot);
+
+        let mut restored = State::<String>::new();
+        restored.restore(&deserialized);
+
+        assert_eq!(restored.value(), "Ban");
+        assert_eq!(restored.snapshot(Some(&String::from("Banana"))), snapshot);
     }
 
-    fn overlay(
-        &mut self,
-        layout: Layout<'_>,
-        _renderer: &Renderer,
-    ) -> Option<overlay::Element<'_, Message, Renderer>> {
-        if self.state.pick_list.is_open {
-            let bounds = layout.bounds();
+    #[test]
+    fn snapshot_restores_the_selected_options() {
+        let mut state = State::new();
+        state.set_value("Ban");
+        state.set_selected_options(vec!["Apple", "Cherry"]);
 
-            let mut menu = Menu::new(
-                &mut self.state.pick_list.menu,
-                &self.options,
-                &self.options_empty_message,
-                &mut self.state.pick_list.hovered_option,
-                &mut self.state.pick_list.last_selection,
-            )
-            .width(bounds.width.round() as u16)
-            .padding(self.padding)
-            .font(self.font.clone())
-            .style(self.style_sheet.menu());
+        let snapshot = state.snapshot(Some(&"Banana"));
 
-            if let Some(size) = self.size {
-                menu = menu.text_size(size);
-            }
+        assert_eq!(snapshot.value, "Ban");
+        assert_eq!(snapshot.selected, Some("Banana"));
+        assert_eq!(snapshot.selected_options, vec!["Apple", "Cherry"]);
 
-            Some(menu.overlay(layout.position(), bounds.height))
-        } else {
-            None
-        }
+        let mut restored = State::<&str>::new();
+        restored.restore(&snapshot);
+
+        assert_eq!(restored.selected_options(), &["Apple", "Cherry"]);
+        assert_eq!(restored.snapshot(Some(&"Banana")), snapshot);
     }
-}
 
-impl<'a, T: 'a, Message, Renderer> Into<Element<'a, Message, Renderer>>
-    for SearchablePickList<'a, T, Message, Renderer>
-where
-    T: Clone + ToString + Eq,
-    [T]: ToOwned<Owned = Vec<T>>,
-    Renderer: text::Renderer + 'a,
-    Message: 'a + Clone,
-{
-    fn into(self) -> Element<'a, Message, Renderer> {
-        Element::new(self)
+    #[test]
+    fn read_only_ignores_clicks() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, Some("Apple"))
+            .on_open(Message::Opened)
+            .read_only(true);
+
+        let messages = update(
+            &mut pick_list,
+            [click(), key_press(keyboard::KeyCode::Down)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert!(messages.is_empty());
+        assert!(!pick_list.state().pick_list.is_open);
+        assert!(!pick_list.state().is_focused());
     }
-}
 
-/// The state of a [`SearchablePickList`].
-#[derive(Debug, Default, Clone)]
-pub struct State<T> {
-    pick_list: pick_list::State<T>,
-    is_focused: bool,
-    is_dragging: bool,
-    is_pasting: Option<Value>,
-    last_click: Option<mouse::Click>,
-    cursor: Cursor,
-    keyboard_modifiers: keyboard::Modifiers,
-    first_click: bool,
-    // TODO: Add stateful horizontal scrolling offset
-}
+    #[test]
+    fn reselecting_the_selected_option_is_reported() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, Some("Apple"));
 
-impl<T: Default> State<T> {
-    /// Creates a new [`State`], representing an unfocused [`SearchablePickList`].
-    pub fn new() -> Self {
-        Self {
-            pick_list: pick_list::State::default(),
-            is_focused: false,
-            is_dragging: false,
-            is_pasting: None,
-            last_click: None,
-            cursor: Cursor::default(),
-            keyboard_modifiers: keyboard::Modifiers::default(),
-            first_click: false,
-        }
+        let messages = update(
+            &mut pick_list,
+            [click(), key_press(keyboard::KeyCode::Enter)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert!(messages.contains(&Message::Selected("Apple")));
+        assert!(!pick_list.state().pick_list.is_open);
     }
 
-    /// Creates a new [`State`], representing a focused [`SearchablePickList`].
-    pub fn focused() -> Self {
-        Self {
-            pick_list: pick_list::State::default(),
-            is_focused: true,
-            is_dragging: false,
-            is_pasting: None,
-            last_click: None,
-            cursor: Cursor::default(),
-            keyboard_modifiers: keyboard::Modifiers::default(),
-            first_click: false,
-        }
+    #[test]
+    fn skip_if_unchanged_ignores_reselections() {
+        let mut state = State::new();
+        let mut pick_list =
+            pick_list(&mut state, Some("Apple")).skip_if_unchanged(true);
+
+        let messages = update(
+            &mut pick_list,
+            [click(), key_press(keyboard::KeyCode::Enter)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert!(!messages.contains(&Message::Selected("Apple")));
+        assert_eq!(pick_list.selected, Some("Apple"));
+        assert!(!pick_list.state().pick_list.is_open);
     }
 
-    /// Returns whether the [`SearchablePickList`] is currently focused or not.
-    pub fn is_focused(&self) -> bool {
-        self.is_focused
+    #[test]
+    fn prefix_shifts_the_text_and_suffix_is_drawn() {
+        let mut renderer = Monospace::default();
+        let mut state = State::new();
+
+        let pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "",
+            Some("Apple"),
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .prefix(String::from("$"))
+        .suffix(String::from("kg"));
+
+        let node = Widget::<Message, Monospace>::layout(
+            &pick_list,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let text_bounds =
+            Layout::new(&node).children().next().unwrap().bounds();
+
+        assert_eq!(text_bounds.x, 10.0);
+        assert_eq!(text_bounds.width, 190.0);
+        assert_eq!(node.size().width, 200.0);
+
+        pick_list.draw(&mut renderer, Layout::new(&node), Point::ORIGIN, None);
+
+        assert!(renderer.drawn.contains(&String::from("$")));
+        assert!(renderer.drawn.contains(&String::from("kg")));
+        assert!(renderer.drawn.contains(&String::from("Apple")));
     }
 
-    /// Returns the [`Cursor`] of the [`SearchablePickList`].
-    pub fn cursor(&self) -> Cursor {
-        self.cursor
-    }
+    #[test]
+    fn placeholder_focused_replaces_the_placeholder_while_focused() {
+        let mut renderer = Monospace::default();
+        let mut state = State::new();
 
-    /// Focuses the [`SearchablePickList`].
-    pub fn focus(&mut self) {
-        self.is_focused = true;
-    }
+        let node = {
+            let pick_list = SearchablePickList::new(
+                &mut state,
+                "Select...",
+                "",
+                None,
+                &OPTIONS[..],
+                Message::Changed,
+                Message::Selected,
+            )
+            .placeholder_focused(String::from("Type to search..."));
 
-    /// Unfocuses the [`SearchablePickList`].
-    pub fn unfocus(&mut self) {
-        self.is_focused = false;
-    }
+            let node = Widget::<Message, Monospace>::layout(
+                &pick_list,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+            );
 
-    /// Moves the [`Cursor`] of the [`SearchablePickList`] to the front of the input text.
-    pub fn move_cursor_to_front(&mut self) {
-        self.cursor.move_to(0);
-    }
+            pick_list.draw(
+                &mut renderer,
+                Layout::new(&node),
+                Point::ORIGIN,
+                None,
+            );
 
-    /// Moves the [`Cursor`] of the [`SearchablePickList`] to the end of the input text.
-    pub fn move_cursor_to_end(&mut self) {
-        self.cursor.move_to(usize::MAX);
-    }
+            node
+        };
 
-    /// Moves the [`Cursor`] of the [`SearchablePickList`] to an arbitrary location.
-    pub fn move_cursor_to(&mut self, position: usize) {
-        self.cursor.move_to(position);
-    }
+        assert!(renderer.drawn.contains(&String::from("Select...")));
+        assert!(!renderer.drawn.contains(&String::from("Type to search...")));
 
-    /// Selects all the content of the [`SearchablePickList`].
-    pub fn select_all(&mut self) {
-        self.cursor.select_range(0, usize::MAX);
-    }
-}
+        state.focus();
+        renderer.drawn.clear();
 
-/// Computes the position of the text cursor at the given X coordinate of
-/// a [`SearchablePickList`].
-pub fn find_cursor_position<Renderer: text::Renderer>(
-    renderer: &Renderer,
-    text_bounds: Rectangle,
-    font: Renderer::Font,
-    size: Option<u16>,
-    value: &Value,
-    is_focused: bool,
-    cursor: Cursor,
-    x: f32,
-) -> usize {
-    let size = size.unwrap_or(renderer.default_size());
+        let pick_list = SearchablePickList::new(
+            &mut state,
+            "Select...",
+            "",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .placeholder_focused(String::from("Type to search..."));
 
-    let offset = offset(
-        renderer, 
-        text_bounds,
-        font.clone(), 
-        size, 
-        &value, 
-        is_focused, 
-        cursor
-    );
-
-    find_cursor_position2(
-        renderer,
-        &value,
-        font.clone(),
-        size,
-        x + offset,
-        0,
-        value.len(),
-    )
-}
+        pick_list.draw(&mut renderer, Layout::new(&node), Point::ORIGIN, None);
 
-// TODO: Reduce allocations
-fn find_cursor_position2<Renderer: text::Renderer>(
-    renderer: &Renderer,
-    value: &Value,
-    font: Renderer::Font,
-    size: u16,
-    target: f32,
-    start: usize,
-    end: usize,
-) -> usize {
-    let measure = |label: &str| -> f32 {
-        let (width, _) = renderer.measure(
-            label,
-            size,
-            font.clone(),
-            Size::new(f32::INFINITY, f32::INFINITY),
+        assert!(renderer.drawn.contains(&String::from("Type to search...")));
+    }
+
+    #[test]
+    fn max_length_ignores_typing_past_it() {
+        let mut state = State::focused();
+        let mut pick_list = pick_list(&mut state, None).max_length(3);
+
+        let messages = update(
+            &mut pick_list,
+            "abcd".chars().map(|c| {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            }),
+            Point::ORIGIN,
         );
 
-        width.round()
-    };    
+        assert_eq!(messages.last(), Some(&Message::Changed("abc".into())));
+        assert_eq!(pick_list.value.to_string(), "abc");
+    }
+
+    #[test]
+    fn max_length_truncates_pasted_text() {
+        /// A clipboard holding more text than fits.
+        struct Long;
 
-    if start >= end {
-        if start == 0 {
-            return 0;
+        impl Clipboard for Long {
+            fn read(&self) -> Option<String> {
+                Some(String::from("bcdef"))
+            }
+
+            fn write(&mut self, _contents: String) {}
         }
-    
-        let prev = value.until(start - 1);
-        let next = value.until(start);
 
-        let prev_width = measure(&prev.to_string());
-        let next_width = measure(&next.to_string());
+        let mut state = State::focused();
+        state.move_cursor_to_end();
 
-        if next_width - target > target - prev_width {
-            return start - 1;
-        } else {
-            return start;
+        let mut pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "a",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .max_length(4);
+
+        let node = Widget::<Message, Null>::layout(
+            &pick_list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        for event in [
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::COMMAND,
+            )),
+            key_press(keyboard::KeyCode::V),
+        ] {
+            let _ = pick_list.on_event(
+                event,
+                Layout::new(&node),
+                Point::ORIGIN,
+                &Null,
+                &mut Long,
+                &mut Shell::new(&mut messages),
+            );
         }
+
+        assert_eq!(messages, [Message::Changed(String::from("abcd"))]);
     }
 
-    let index = (end - start) / 2;
-    let subvalue = value.until(start + index);
+    #[test]
+    fn enter_submits_when_no_option_is_highlighted() {
+        let mut state = State::focused();
+        let mut pick_list =
+            pick_list(&mut state, None).on_submit(Message::Custom);
 
-    let width = measure(&subvalue.to_string());
+        let messages = update(
+            &mut pick_list,
+            "xyz"
+                .chars()
+                .map(|c| Event::Keyboard(keyboard::Event::CharacterReceived(c)))
+                .chain([key_press(keyboard::KeyCode::Enter)]),
+            Point::ORIGIN,
+        );
 
-    if width > target {
-        find_cursor_position2(
-            renderer,
-            value,
-            font,
-            size,
-            target,
-            start,
-            start + index,
-        )
-    } else {
-        find_cursor_position2(
-            renderer,
-            value,
-            font,
-            size,
-            target,
-            start + index + 1,
-            end,
-        )
+        assert_eq!(messages.last(), Some(&Message::Custom));
+        assert!(!messages
+            .iter()
+            .any(|message| matches!(message, Message::Selected(_))));
     }
-}
 
-mod platform {
-    use crate::keyboard;
+    #[test]
+    fn selection_is_only_drawn_while_open() {
+        let mut renderer = Monospace::default();
+        let mut state = State::focused();
+        state.select_all();
 
-    pub fn is_jump_modifier_pressed(modifiers: keyboard::Modifiers) -> bool {
-        if cfg!(target_os = "macos") {
-            modifiers.alt()
-        } else {
-            modifiers.control()
-        }
-    }
-}
+        let is_selection = |bounds: &Rectangle| bounds.width == 50.0;
 
+        let node = {
+            let pick_list = SearchablePickList::new(
+                &mut state,
+                "Search...",
+                "Apple",
+                Some("Cherry"),
+                &OPTIONS[..],
+                Message::Changed,
+                Message::Selected,
+            );
 
-fn measure_value<Renderer>(
-    renderer: &Renderer, 
-    value: &str, 
-    size: u16,
-    font: &Renderer::Font
-) -> f32 
-where
-    Renderer: text::Renderer,
-{
-    let (width, _) = renderer
-    .measure(
-        value, 
-        size, 
-        font.clone(), 
-        Size::INFINITY)
-        ;
-    width
-}
+            let node = Widget::<Message, Monospace>::layout(
+                &pick_list,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            );
 
-fn offset<Renderer>(
-    renderer: &Renderer,
-    text_bounds: Rectangle,
-    font: Renderer::Font,
-    size: u16,
-    value: &text_input_shared::value::Value,
-    is_focused: bool,
-    cursor: text_input_shared::cursor::Cursor,
-) -> f32 
-where
-    Renderer: text::Renderer,
-{
-    if is_focused {
-        let focus_position = match cursor.state(value) {
-            cursor::State::Index(i) => i,
-            cursor::State::Selection { end, .. } => end,
+            pick_list.draw(
+                &mut renderer,
+                Layout::new(&node),
+                Point::ORIGIN,
+                None,
+            );
+
+            node
         };
 
-        let (_, offset) = measure_cursor_and_scroll_offset(
-            renderer,
-            text_bounds,
-            value,
-            size,
-            focus_position,
-            font,
-        );
+        assert!(renderer.drawn.contains(&String::from("Cherry")));
+        assert!(!renderer.quads.iter().any(is_selection));
 
-        offset
-    } else {
-        0.0
-    }
-}
+        state.open();
+        renderer.quads.clear();
 
-/// null
-pub fn draw<T, Renderer>(
-    renderer: &mut Renderer,
-    bounds: Rectangle,
-    mut text_bounds: Rectangle,
-    cursor_position: Point,
-    pick_list_is_open: bool,
-    selected: Option<&T>,
-    font: &Renderer::Font,
-    text_size: Option<u16>,
-    placeholder: &str,
-    padding: Padding,
-    value: &Value,
-    is_focused: bool,
-    _cursor: text_input_shared::cursor::Cursor,
-    style_sheet: &dyn StyleSheet,
-) where
-    Renderer: text::Renderer,
-    T: ToString,
-{
-    if pick_list_is_open {
-        text_bounds.width -= 30.0;
+        let pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "Apple",
+            Some("Cherry"),
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        );
 
-        let is_mouse_over_text = bounds.contains(cursor_position);
+        pick_list.draw(&mut renderer, Layout::new(&node), Point::ORIGIN, None);
 
-        let style = if is_focused {
-            style_sheet.text_input_focused()
-        } else if is_mouse_over_text {
-            style_sheet.text_input_hovered()
-        } else {
-            style_sheet.text_input_active()
+        assert!(renderer.quads.iter().any(is_selection));
+    }
+
+    #[test]
+    fn wheel_cycle_steps_through_the_options() {
+        let scroll = |y| {
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y },
+            })
         };
 
-        renderer.fill_text(Text {
-            content: &Renderer::ARROW_DOWN_ICON.to_string(),
-            font: Renderer::ICON_FONT,
-            size: bounds.height * style_sheet.icon_size(),
-            bounds: Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(padding.horizontal()),
-                y: bounds.center_y(),
-                ..bounds
-            },
-            color: style_sheet.text_input_value_color(),
-            horizontal_alignment: alignment::Horizontal::Right,
-            vertical_alignment: alignment::Vertical::Center,
-        });
+        let mut state = State::new();
+        let mut pick_list =
+            pick_list(&mut state, Some("Apple")).wheel_cycle(true);
 
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds,
-                border_radius: style.border_radius,
-                border_width: style.border_width,
-                border_color: style.border_color,
-            },
-            style.background
+        let messages = update(
+            &mut pick_list,
+            [scroll(-1.0), scroll(-1.0), scroll(-1.0), scroll(1.0)],
+            Point::new(10.0, 10.0),
         );
 
-        let text = value.to_string();
+        assert_eq!(
+            messages,
+            vec![
+                Message::Selected("Banana"),
+                Message::Selected("Cherry"),
+                Message::Selected("Banana"),
+            ]
+        );
+        assert_eq!(pick_list.selected, Some("Banana"));
+        assert!(!pick_list.state().pick_list.is_open);
+    }
 
-        renderer.fill_text(Text {
-            content: if text.is_empty() { placeholder } else { &text },
-            color: if text.is_empty() {
-                style_sheet.text_input_placeholder_color()
-            } else {
-                style_sheet.text_input_value_color()
-            },
-            font: font.clone(),
-            bounds: Rectangle {
-                y: text_bounds.center_y(),
-                width: f32::INFINITY,
-                ..text_bounds
-            },
-            size: f32::from(text_size.unwrap_or(renderer.default_size())),
-            horizontal_alignment: alignment::Horizontal::Left,
-            vertical_alignment: alignment::Vertical::Center,
-        });
+    #[test]
+    fn wheel_cycle_is_opt_in() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, Some("Apple"));
 
-    } else {
-        let is_mouse_over = bounds.contains(cursor_position);
-        let is_selected = selected.is_some();
+        let messages = update(
+            &mut pick_list,
+            [Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+            })],
+            Point::new(10.0, 10.0),
+        );
 
-        let style = if is_mouse_over {
-            style_sheet.pick_list_hovered()
-        } else {
-            style_sheet.pick_list_active()
-        };
+        assert!(messages.is_empty());
+        assert_eq!(pick_list.selected, Some("Apple"));
+    }
 
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds,
-                border_color: style.border_color,
-                border_width: style.border_width,
-                border_radius: style.border_radius,
-            },
-            style.background
+    #[test]
+    fn right_to_left_moves_the_arrow_to_the_left() {
+        let mut state = State::new();
+        let pick_list = pick_list(&mut state, None)
+            .layout_direction(LayoutDirection::RightToLeft);
+
+        let node = Widget::<Message, Null>::layout(
+            &pick_list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
         );
+        let layout = Layout::new(&node);
+        let bounds = field_bounds(layout, pick_list.padding);
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let arrow = arrow_bounds(bounds, pick_list.padding, ARROW_WIDTH, true);
 
-        renderer.fill_text(Text {
-            content: &Renderer::ARROW_DOWN_ICON.to_string(),
-            font: Renderer::ICON_FONT,
-            size: bounds.height * style.icon_size,
-            bounds: Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(padding.horizontal()),
-                y: bounds.center_y(),
-                ..bounds
-            },
-            color: style_sheet.text_input_value_color(),
-            horizontal_alignment: alignment::Horizontal::Right,
-            vertical_alignment: alignment::Vertical::Center,
-        });
+        assert_eq!(pick_list.text_alignment, alignment::Horizontal::Right);
+        assert_eq!(arrow.x, bounds.x);
+        assert_eq!(arrow.x + arrow.width, text_bounds.x);
+        assert_eq!(
+            clear_icon_bounds(bounds, pick_list.padding, ARROW_WIDTH, true).x,
+            arrow.x + arrow.width
+        );
+    }
 
-        let label = selected.map(ToString::to_string);
-    
-        if let Some(label) =
-            label.as_ref().map(String::as_str).or_else(|| Some(placeholder))
-        {
-            let text_size = f32::from(text_size.unwrap_or(renderer.default_size()));
-    
-            renderer.fill_text(Text {
-                content: label,
-                size: text_size,
-                font: font.clone(),
-                color: is_selected
-                    .then(|| style.text_color)
-                    .unwrap_or(style.placeholder_color),
-                bounds: Rectangle {
-                    x: bounds.x + f32::from(padding.left),
-                    y: bounds.center_y() - text_size / 2.0,
-                    width: bounds.width - f32::from(padding.horizontal()),
-                    height: text_size,
-                },
-                horizontal_alignment: alignment::Horizontal::Left,
-                vertical_alignment: alignment::Vertical::Top,
-            });
+    #[test]
+    fn full_label_is_only_shown_when_truncated() {
+        let renderer = Monospace::default();
+
+        for (width, expected) in [(3000.0, None), (60.0, Some("Banana"))] {
+            let mut state = State::new();
+            let mut pick_list = SearchablePickList::new(
+                &mut state,
+                "Search...",
+                "",
+                Some("Banana"),
+                &OPTIONS[..],
+                Message::Changed,
+                Message::Selected,
+            )
+            .show_full_on_hover(true);
+
+            let node = Widget::<Message, Monospace>::layout(
+                &pick_list,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(width, 100.0)),
+            );
+            let layout = Layout::new(&node);
+
+            assert_eq!(pick_list.full_label(layout, &renderer), None);
+
+            let mut messages = Vec::new();
+
+            let _ = pick_list.on_event(
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: Point::new(10.0, 10.0),
+                }),
+                layout,
+                Point::new(10.0, 10.0),
+                &renderer,
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+            );
+
+            assert_eq!(
+                pick_list.full_label(layout, &renderer).as_deref(),
+                expected
+            );
+            assert_eq!(
+                pick_list.overlay(layout, &renderer).is_some(),
+                expected.is_some()
+            );
         }
     }
-}
 
-fn measure_cursor_and_scroll_offset<Renderer>(
-    renderer: &Renderer,
-    text_bounds: Rectangle,
-    value: &Value,
-    size: u16,
-    cursor_index: usize,
-    font: Renderer::Font,
-) -> (f32, f32)
-where
-    Renderer: text::Renderer,
-{
-    let text_before_cursor = value.until(cursor_index).to_string();
+    #[test]
+    fn dedup_collapses_options_with_the_same_label() {
+        let options = ["Apple", "Banana", "Apple", "Cherry"];
+
+        for (dedup, visible, indices) in [
+            (false, &["Apple", "Banana", "Apple"][..], vec![0, 1, 2]),
+            (true, &["Apple", "Banana"][..], vec![0, 1]),
+        ] {
+            let mut state = State::new();
+            let mut pick_list = SearchablePickList::<_, _, Null>::new(
+                &mut state,
+                "Search...",
+                "",
+                None,
+                &options[..],
+                Message::Changed,
+                Message::Selected,
+            )
+            .dedup(dedup);
+
+            let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+            let _ =
+                update(&mut pick_list, type_text("a"), Point::new(10.0, 10.0));
+
+            assert_eq!(pick_list.visible_options(), visible);
+            assert_eq!(pick_list.state().filtered_indices, Some(indices));
+        }
+    }
+
+    #[test]
+    fn deferred_toggles_are_batched_until_close() {
+        let mut state = State::new();
+        let mut pick_list = pick_list(&mut state, None)
+            .multi_select(true)
+            .on_toggle(Message::Toggled)
+            .defer_selection_until_close(true)
+            .on_selections(Message::Selections);
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+
+        let messages = update(
+            &mut pick_list,
+            [
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Enter),
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Enter),
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Enter),
+            ],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(messages, vec![]);
+        assert_eq!(
+            pick_list.state().selected_options(),
+            &["Apple", "Banana", "Cherry"]
+        );
+
+        let messages = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Escape)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(
+            messages,
+            vec![Message::Selections(vec!["Apple", "Banana", "Cherry"])]
+        );
+
+        let _ = update(&mut pick_list, [click()], Point::new(10.0, 10.0));
+        let messages = update(
+            &mut pick_list,
+            [key_press(keyboard::KeyCode::Escape)],
+            Point::new(10.0, 10.0),
+        );
+
+        assert_eq!(messages, vec![]);
+    }
+
+    #[test]
+    fn disabled_placeholder_uses_the_disabled_text_color() {
+        let mut renderer = Monospace::default();
+        let mut state = State::new();
 
-    let text_value_width = measure_value(renderer, &text_before_cursor, size, &font);
-    let offset = ((text_value_width + 5.0) - text_bounds.width).max(0.0);
+        let pick_list = SearchablePickList::new(
+            &mut state,
+            "Search...",
+            "",
+            None,
+            &OPTIONS[..],
+            Message::Changed,
+            Message::Selected,
+        )
+        .disabled_placeholder(String::from("Unavailable"))
+        .enabled(false);
+
+        let node = Widget::<Message, Monospace>::layout(
+            &pick_list,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+        );
+
+        pick_list.draw(&mut renderer, Layout::new(&node), Point::ORIGIN, None);
 
-    (text_value_width, offset)
+        let index = renderer
+            .drawn
+            .iter()
+            .position(|text| text == "Unavailable")
+            .expect("Disabled placeholder drawn");
+
+        assert!(!renderer.drawn.contains(&String::from("Search...")));
+        assert_eq!(
+            renderer.colors[index],
+            pick_list.style_sheet.disabled().text_color
+        );
+        assert_ne!(
+            renderer.colors[index],
+            pick_list.style_sheet.disabled().placeholder_color
+        );
+    }
 }
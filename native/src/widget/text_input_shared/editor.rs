@@ -25,8 +25,15 @@ impl<'a> Editor<'a> {
             _ => {}
         }
 
+        let length = self.value.len();
+
         self.value.insert(self.cursor.end(self.value), character);
-        self.cursor.move_right(self.value);
+
+        // A combining character joins the grapheme before it, so the cursor
+        // only moves past the graphemes that were actually added
+        let amount = self.value.len().saturating_sub(length);
+
+        self.cursor.move_right_by_amount(self.value, amount);
     }
 
     pub fn paste(&mut self, content: Value) {
@@ -0,0 +1,96 @@
+//! Keep track of the edits of a text input to undo and redo them.
+use crate::widget::text_input_shared::cursor::Cursor;
+use crate::widget::text_input_shared::value::Value;
+
+/// The maximum amount of edits that can be undone.
+const MAX_ENTRIES: usize = 100;
+
+/// The undo and redo history of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+    is_inserting: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Creates a new empty [`History`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the [`Value`] and [`Cursor`] of a text input right before an
+    /// edit.
+    pub fn record(&mut self, value: &Value, cursor: Cursor) {
+        self.push(value, cursor);
+        self.is_inserting = false;
+    }
+
+    /// Records the [`Value`] and [`Cursor`] of a text input right before a
+    /// character is inserted.
+    ///
+    /// Consecutive insertions are coalesced into a single edit.
+    pub fn record_insert(&mut self, value: &Value, cursor: Cursor) {
+        if !self.is_inserting {
+            self.push(value, cursor);
+            self.is_inserting = true;
+        }
+    }
+
+    /// Ends the current edit, so the next insertion starts a new one.
+    pub fn seal(&mut self) {
+        self.is_inserting = false;
+    }
+
+    /// Reverts the last edit, returning whether there was anything to undo.
+    pub fn undo(&mut self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        self.is_inserting = false;
+
+        Self::swap(&mut self.undo, &mut self.redo, value, cursor)
+    }
+
+    /// Reapplies the last undone edit, returning whether there was anything
+    /// to redo.
+    pub fn redo(&mut self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        self.is_inserting = false;
+
+        Self::swap(&mut self.redo, &mut self.undo, value, cursor)
+    }
+
+    fn push(&mut self, value: &Value, cursor: Cursor) {
+        self.undo.push(Entry {
+            value: value.clone(),
+            cursor,
+        });
+        self.redo.clear();
+
+        if self.undo.len() > MAX_ENTRIES {
+            let _ = self.undo.remove(0);
+        }
+    }
+
+    fn swap(
+        from: &mut Vec<Entry>,
+        to: &mut Vec<Entry>,
+        value: &mut Value,
+        cursor: &mut Cursor,
+    ) -> bool {
+        match from.pop() {
+            Some(entry) => {
+                to.push(Entry {
+                    value: std::mem::replace(value, entry.value),
+                    cursor: std::mem::replace(cursor, entry.cursor),
+                });
+
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -2,4 +2,5 @@
 #![allow(missing_debug_implementations)]
 pub mod cursor;
 pub mod editor;
+pub mod history;
 pub mod value;
@@ -4,7 +4,7 @@ use unicode_segmentation::UnicodeSegmentation;
 ///
 /// [`TextInput`]: crate::widget::TextInput
 // TODO: Reduce allocations, cache results (?)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Value {
     graphemes: Vec<String>,
 }
@@ -90,6 +90,15 @@ impl Value {
         Self { graphemes }
     }
 
+    /// Returns the byte offset of the given grapheme `index` in the `String`
+    /// returned by [`Value::to_string`].
+    pub fn byte_offset(&self, index: usize) -> usize {
+        self.graphemes[..index.min(self.len())]
+            .iter()
+            .map(String::len)
+            .sum()
+    }
+
     /// Converts the [`Value`] into a `String`.
     pub fn to_string(&self) -> String {
         self.graphemes.concat()
@@ -119,7 +119,10 @@ pub mod scrollable {
 pub mod searchable_pick_list {
     //! Display a dropdown list of selectable values.
     pub use iced_native::overlay::menu::Style as Menu;
-    pub use iced_native::widget::searchable_pick_list::{State, StyleSheet};
+    pub use iced_native::widget::searchable_pick_list::{
+        Deferred, LayoutDirection, MatchHighlightMode, Snapshot, State,
+        StyleSheet,
+    };
 
     /// A widget allowing the selection of a single value from a list of options.
     pub type SearchablePickList<'a, T, Message> =
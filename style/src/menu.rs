@@ -1,18 +1,52 @@
+use crate::scrollable::{Scrollbar, Scroller};
 use iced_core::{Background, Color};
 
 /// The appearance of a menu.
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
+    /// The text color of the options.
     pub text_color: Color,
+    /// The background of the menu.
     pub background: Background,
+    /// The width of the border of the menu.
     pub border_width: f32,
+    /// The color of the border of the menu.
     pub border_color: Color,
+    /// The text color of the highlighted option.
     pub selected_text_color: Color,
+    /// The background of the highlighted option.
     pub selected_background: Background,
+    /// The text color of the option under the mouse, which defaults to the
+    /// `selected_text_color`.
+    pub hovered_text_color: Option<Color>,
+    /// The background of the option under the mouse, which defaults to the
+    /// `selected_background`.
+    pub hovered_background: Option<Background>,
+    /// The appearance of the scrollbar of the menu.
+    pub scrollbar: Scrollbar,
+    /// The appearance of the scrollbar of the menu while it is hovered.
+    pub hovered_scrollbar: Scrollbar,
+    /// The width of the scrollbar of the menu.
+    pub scrollbar_width: u16,
+    /// The width of the scroller of the menu.
+    pub scroller_width: u16,
 }
 
 impl std::default::Default for Style {
     fn default() -> Self {
+        let scrollbar = Scrollbar {
+            background: None,
+            border_radius: 5.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            scroller: Scroller {
+                color: [0.0, 0.0, 0.0, 0.7].into(),
+                border_radius: 5.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+        };
+
         Self {
             text_color: Color::BLACK,
             background: Background::Color([0.87, 0.87, 0.87].into()),
@@ -20,6 +54,17 @@ impl std::default::Default for Style {
             border_color: [0.7, 0.7, 0.7].into(),
             selected_text_color: Color::WHITE,
             selected_background: Background::Color([0.4, 0.4, 1.0].into()),
+            hovered_text_color: None,
+            hovered_background: None,
+            scrollbar,
+            hovered_scrollbar: Scrollbar {
+                background: Some(Background::Color(
+                    [0.0, 0.0, 0.0, 0.3].into(),
+                )),
+                ..scrollbar
+            },
+            scrollbar_width: 10,
+            scroller_width: 10,
         }
     }
 }
@@ -7,6 +7,32 @@ use crate::{menu, pick_list, text_input};
 pub trait StyleSheet {
     fn menu(&self) -> menu::Style;
 
+    fn options_empty_message_color(&self) -> Color {
+        self.menu().text_color
+    }
+
+    fn match_highlight_background(&self) -> Background {
+        Background::Color(Color {
+            a: 0.3,
+            ..self.text_input_selection_color()
+        })
+    }
+
+    fn matched_text_color(&self) -> Color {
+        self.text_input_selection_color()
+    }
+
+    fn checkmark_color(&self) -> Color {
+        self.menu().text_color
+    }
+
+    fn group_header_color(&self) -> Color {
+        Color {
+            a: 0.6,
+            ..self.menu().text_color
+        }
+    }
+
     fn icon_size(&self) -> f32 {
         0.7
     }
@@ -25,9 +51,53 @@ pub trait StyleSheet {
         self.text_input_active()
     }
 
+    fn helper_text_color(&self) -> Color {
+        self.text_input_placeholder_color()
+    }
+
+    fn error_text_color(&self) -> Color {
+        Color::from_rgb(0.8, 0.2, 0.2)
+    }
+
+    fn underline_active_color(&self) -> Color {
+        self.text_input_active().border_color
+    }
+
+    fn underline_focused_color(&self) -> Color {
+        self.text_input_focused().border_color
+    }
+
     fn pick_list_active(&self) -> pick_list::Style;
 
     fn pick_list_hovered(&self) -> pick_list::Style;
+
+    fn pick_list_active_text_color(&self) -> Color {
+        self.pick_list_active().text_color
+    }
+
+    fn pick_list_hovered_text_color(&self) -> Color {
+        self.pick_list_hovered().text_color
+    }
+
+    fn disabled(&self) -> pick_list::Style {
+        let active = self.pick_list_active();
+
+        pick_list::Style {
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            placeholder_color: Color {
+                a: active.placeholder_color.a * 0.5,
+                ..active.placeholder_color
+            },
+            border_color: Color {
+                a: active.border_color.a * 0.5,
+                ..active.border_color
+            },
+            ..active
+        }
+    }
 }
 
 struct Default;
@@ -66,11 +136,23 @@ impl StyleSheet for Default {
     }
 
     fn pick_list_active(&self) -> pick_list::Style {
-        todo!()
+        pick_list::Style {
+            text_color: self.text_input_value_color(),
+            placeholder_color: self.text_input_placeholder_color(),
+            background: Background::Color(Color::WHITE),
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            icon_size: 0.7,
+        }
     }
 
     fn pick_list_hovered(&self) -> pick_list::Style {
-        todo!()
+        pick_list::Style {
+            text_color: Color::from_rgb(0.1, 0.1, 0.1),
+            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            ..self.pick_list_active()
+        }
     }
 }
 
@@ -88,3 +170,45 @@ where
         Box::new(style)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_pick_list_styles_do_not_panic() {
+        let style_sheet: Box<dyn StyleSheet> = std::default::Default::default();
+
+        let active = style_sheet.pick_list_active();
+        let hovered = style_sheet.pick_list_hovered();
+
+        assert_eq!(active.border_radius, 5.0);
+        assert_ne!(active.border_color, hovered.border_color);
+    }
+
+    #[test]
+    fn disabled_style_grays_out_the_active_style() {
+        let style_sheet: Box<dyn StyleSheet> = std::default::Default::default();
+
+        let active = style_sheet.pick_list_active();
+        let disabled = style_sheet.disabled();
+
+        assert_eq!(disabled.text_color.a, active.text_color.a * 0.5);
+        assert_eq!(disabled.border_color.a, active.border_color.a * 0.5);
+        assert_eq!(disabled.border_radius, active.border_radius);
+    }
+
+    #[test]
+    fn hovering_changes_the_closed_text_color() {
+        let style_sheet: Box<dyn StyleSheet> = std::default::Default::default();
+
+        assert_eq!(
+            style_sheet.pick_list_active_text_color(),
+            style_sheet.pick_list_active().text_color
+        );
+        assert_ne!(
+            style_sheet.pick_list_active_text_color(),
+            style_sheet.pick_list_hovered_text_color()
+        );
+    }
+}